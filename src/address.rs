@@ -0,0 +1,828 @@
+//! Structured forms of address elements.
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::IssuerCountry;
+
+/// A postal code in the canonical form for its country.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum PostalCode {
+    /// A five digit ZIP code with the ZIP+4 extension when it is known.
+    UnitedStates {
+        zip: String,
+        plus_four: Option<String>,
+    },
+    /// A Canadian postal code, formatted as `A1A 1A1`.
+    Canada(String),
+    /// A five digit Mexican postal code.
+    Mexico(String),
+    /// A value that was not a valid postal code for its country, trimmed but
+    /// otherwise as it appeared.
+    Other(String),
+}
+
+impl PostalCode {
+    /// Parse a postal code from a `DAK` element for a document from
+    /// `country`.
+    ///
+    /// Spaces and hyphens are ignored. Barcodes pad postal codes with zeros,
+    /// so a ZIP+4 extension of `0000` and trailing zeros after a complete
+    /// code are dropped.
+    pub fn parse(value: &str, country: IssuerCountry) -> Self {
+        let compact: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .collect::<String>()
+            .to_ascii_uppercase();
+
+        let parsed = match country {
+            IssuerCountry::UnitedStates => Self::parse_united_states(&compact),
+            IssuerCountry::Canada => Self::parse_canada(&compact),
+            IssuerCountry::Mexico => Self::parse_mexico(&compact),
+        };
+
+        parsed.unwrap_or_else(|| Self::Other(value.trim().to_string()))
+    }
+
+    fn parse_united_states(value: &str) -> Option<Self> {
+        if value.len() < 5 || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let (zip, rest) = value.split_at(5);
+
+        let plus_four = match rest.len() {
+            0 => None,
+            4.. if rest[4..].bytes().all(|b| b == b'0') => {
+                Some(&rest[..4]).filter(|plus_four| *plus_four != "0000")
+            }
+            _ if is_padding(rest) => None,
+            _ => return None,
+        };
+
+        Some(Self::UnitedStates {
+            zip: zip.to_string(),
+            plus_four: plus_four.map(str::to_string),
+        })
+    }
+
+    fn parse_canada(value: &str) -> Option<Self> {
+        if value.len() < 6 || !value.is_ascii() || !is_padding(&value[6..]) {
+            return None;
+        }
+
+        let code = &value[..6];
+        let valid = code.bytes().enumerate().all(|(index, b)| {
+            if index % 2 == 0 {
+                b.is_ascii_alphabetic()
+            } else {
+                b.is_ascii_digit()
+            }
+        });
+
+        valid.then(|| Self::Canada(format!("{} {}", &code[..3], &code[3..])))
+    }
+
+    fn parse_mexico(value: &str) -> Option<Self> {
+        if value.len() < 5 || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let (code, rest) = value.split_at(5);
+
+        is_padding(rest).then(|| Self::Mexico(code.to_string()))
+    }
+
+    /// The postal code as it should be written in a barcode.
+    pub(crate) fn to_element(&self) -> String {
+        match self {
+            Self::UnitedStates { zip, plus_four } => {
+                format!("{zip}{}", plus_four.as_deref().unwrap_or("0000"))
+            }
+            Self::Canada(code) => code.replace(' ', ""),
+            Self::Mexico(code) | Self::Other(code) => code.clone(),
+        }
+    }
+}
+
+impl std::fmt::Display for PostalCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnitedStates {
+                zip,
+                plus_four: Some(plus_four),
+            } => write!(f, "{zip}-{plus_four}"),
+            Self::UnitedStates {
+                zip,
+                plus_four: None,
+            } => write!(f, "{zip}"),
+            Self::Canada(code) | Self::Mexico(code) | Self::Other(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+/// If the rest of a value is zeros added to fill out the element.
+fn is_padding(value: &str) -> bool {
+    value.bytes().all(|b| b == b'0')
+}
+
+/// A street address line split into its parts, with abbreviations
+/// standardized.
+///
+/// US addresses use the USPS Publication 28 abbreviations. Canadian addresses
+/// use Canada Post's, including French street types that come before the
+/// street name, directionals such as `O` for west, and unit numbers written
+/// before the civic number as in `12-345 MAIN ST`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct StreetAddress {
+    pub house_number: Option<String>,
+    pub pre_directional: Option<Directional>,
+    /// A street type that comes before the name, such as `HWY` in
+    /// `HWY 52` or `RUE` in `RUE PRINCIPALE`.
+    pub pre_type: Option<String>,
+    pub street_name: String,
+    pub suffix: Option<String>,
+    pub post_directional: Option<Directional>,
+    pub unit: Option<Unit>,
+}
+
+/// A secondary unit within a building, such as an apartment or suite.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct Unit {
+    /// The standardized designator, such as `APT` or `#`, or nothing when only
+    /// a number was given.
+    pub designator: Option<String>,
+    pub number: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum Directional {
+    North,
+    South,
+    East,
+    West,
+    Northeast,
+    Northwest,
+    Southeast,
+    Southwest,
+}
+
+impl Directional {
+    fn parse(token: &str, country: IssuerCountry) -> Option<Self> {
+        use Directional::*;
+
+        let directional = match token {
+            "N" | "NORTH" => North,
+            "S" | "SOUTH" => South,
+            "E" | "EAST" => East,
+            "W" | "WEST" => West,
+            "NE" | "NORTHEAST" => Northeast,
+            "NW" | "NORTHWEST" => Northwest,
+            "SE" | "SOUTHEAST" => Southeast,
+            "SW" | "SOUTHWEST" => Southwest,
+            _ if country == IssuerCountry::Canada => match token {
+                "NORD" => North,
+                "SUD" => South,
+                "EST" => East,
+                "O" | "OUEST" => West,
+                "NORD-EST" => Northeast,
+                "NO" | "NORD-OUEST" => Northwest,
+                "SUD-EST" => Southeast,
+                "SO" | "SUD-OUEST" => Southwest,
+                _ => return None,
+            },
+            _ => return None,
+        };
+
+        Some(directional)
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Self::North => "N",
+            Self::South => "S",
+            Self::East => "E",
+            Self::West => "W",
+            Self::Northeast => "NE",
+            Self::Northwest => "NW",
+            Self::Southeast => "SE",
+            Self::Southwest => "SW",
+        }
+    }
+}
+
+impl std::fmt::Display for Directional {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+impl StreetAddress {
+    /// Parse a street address line for a document from `country`.
+    ///
+    /// Returns `None` for lines without a street name, including post office
+    /// boxes.
+    pub fn parse(line: &str, country: IssuerCountry) -> Option<Self> {
+        let mut tokens: Vec<String> = line
+            .split_whitespace()
+            .map(|token| token.trim_matches(|c| c == ',' || c == '.'))
+            .filter(|token| !token.is_empty())
+            .map(str::to_ascii_uppercase)
+            .collect();
+
+        if is_post_office_box(&tokens, country) {
+            return None;
+        }
+
+        // The house number and street name come before any unit.
+        let mut address = Self {
+            unit: Unit::take(&mut tokens, country, 2),
+            ..Default::default()
+        };
+
+        let mut tokens = tokens.as_slice();
+
+        if let Some((first, rest)) = tokens.split_first()
+            && first.starts_with(|c: char| c.is_ascii_digit())
+        {
+            match first.split_once('-') {
+                // Canadian addresses put the unit before the civic number.
+                Some((unit, number))
+                    if country == IssuerCountry::Canada
+                        && address.unit.is_none()
+                        && !unit.is_empty()
+                        && !number.is_empty() =>
+                {
+                    address.unit = Some(Unit {
+                        designator: None,
+                        number: Some(unit.to_string()),
+                    });
+                    address.house_number = Some(number.to_string());
+                }
+                _ => address.house_number = Some(first.clone()),
+            }
+            tokens = rest;
+
+            // Fractional house numbers, such as `110 1/2`.
+            if let Some((fraction, rest)) = tokens.split_first()
+                && fraction.contains('/')
+                && fraction.bytes().all(|b| b.is_ascii_digit() || b == b'/')
+            {
+                if let Some(house_number) = &mut address.house_number {
+                    house_number.push(' ');
+                    house_number.push_str(fraction);
+                }
+                tokens = rest;
+            }
+        }
+
+        // A directional is only part of the street name when nothing but a
+        // suffix follows it, as in `NORTH ST`.
+        if let [first, rest @ ..] = tokens
+            && let Some(directional) = Directional::parse(first, country)
+            && (rest.len() > 1 || rest.first().is_some_and(|t| suffix(t, country).is_none()))
+        {
+            address.pre_directional = Some(directional);
+            tokens = rest;
+        }
+
+        if let [rest @ .., last] = tokens
+            && !rest.is_empty()
+            && let Some(directional) = Directional::parse(last, country)
+        {
+            address.post_directional = Some(directional);
+            tokens = rest;
+        }
+
+        if let [rest @ .., last] = tokens
+            && !rest.is_empty()
+            && let Some(suffix) = suffix(last, country)
+        {
+            address.suffix = Some(suffix.to_string());
+            tokens = rest;
+        } else if let [first, rest @ ..] = tokens
+            && !rest.is_empty()
+            && let Some(pre_type) = pre_type(first, country)
+        {
+            address.pre_type = Some(pre_type.to_string());
+            tokens = rest;
+
+            // `COUNTY ROAD 5` is written `CR 5`.
+            if first == "COUNTY"
+                && let [road, rest @ ..] = tokens
+                && matches!(road.as_str(), "ROAD" | "RD")
+                && !rest.is_empty()
+            {
+                tokens = rest;
+            }
+        }
+
+        if tokens.is_empty() {
+            return None;
+        }
+
+        address.street_name = tokens.join(" ");
+
+        Some(address)
+    }
+}
+
+impl std::fmt::Display for StreetAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::new();
+
+        match (&self.house_number, &self.unit) {
+            (
+                Some(house_number),
+                Some(Unit {
+                    designator: None,
+                    number: Some(number),
+                }),
+            ) => parts.push(format!("{number}-{house_number}")),
+            (Some(house_number), _) => parts.push(house_number.clone()),
+            (None, _) => (),
+        }
+
+        parts.extend(self.pre_directional.map(|d| d.to_string()));
+        parts.extend(self.pre_type.clone());
+        parts.push(self.street_name.clone());
+        parts.extend(self.suffix.clone());
+        parts.extend(self.post_directional.map(|d| d.to_string()));
+
+        if let Some(unit) = &self.unit
+            && unit.designator.is_some()
+        {
+            parts.push(unit.to_string());
+        }
+
+        f.write_str(&parts.join(" "))
+    }
+}
+
+impl Unit {
+    /// Parse a line that contains only a unit, such as a second address line
+    /// of `APT 4`.
+    pub fn parse(line: &str, country: IssuerCountry) -> Option<Self> {
+        let mut tokens: Vec<String> = line
+            .split_whitespace()
+            .map(str::to_ascii_uppercase)
+            .collect();
+
+        let unit = Self::take(&mut tokens, country, 0)?;
+
+        tokens.is_empty().then_some(unit)
+    }
+
+    /// Remove a unit designator and everything after it from the tokens of
+    /// an address line, looking for the designator from `start` onwards.
+    ///
+    /// Words like `PIER` and `LOWER` are also street names, so a designator
+    /// must start the line, follow the street suffix, or be at the end of the
+    /// line with at most a number after it.
+    fn take(tokens: &mut Vec<String>, country: IssuerCountry, start: usize) -> Option<Self> {
+        let (index, unit) = tokens
+            .iter()
+            .enumerate()
+            .skip(start)
+            .find_map(|(index, token)| {
+                let rest = Some(tokens[index + 1..].join(" ")).filter(|rest| !rest.is_empty());
+
+                if let Some(number) = token.strip_prefix('#') {
+                    let number = Some(number)
+                        .filter(|number| !number.is_empty())
+                        .map(str::to_string)
+                        .or(rest);
+
+                    return Some((
+                        index,
+                        Self {
+                            designator: Some("#".to_string()),
+                            number,
+                        },
+                    ));
+                }
+
+                let (designator, needs_number) = unit_designator(token, country)?;
+
+                if needs_number && rest.is_none() {
+                    return None;
+                }
+
+                let follows_suffix = match &tokens[..index] {
+                    [] => true,
+                    [.., before, last] if Directional::parse(last, country).is_some() => {
+                        suffix(before, country).is_some()
+                    }
+                    [.., last] => suffix(last, country).is_some(),
+                };
+                let ends_line = match &tokens[index + 1..] {
+                    [] => true,
+                    [number] => is_unit_number(number),
+                    _ => false,
+                };
+
+                if !follows_suffix && !ends_line {
+                    return None;
+                }
+
+                Some((
+                    index,
+                    Self {
+                        designator: Some(designator.to_string()),
+                        number: rest,
+                    },
+                ))
+            })?;
+
+        tokens.truncate(index);
+
+        Some(unit)
+    }
+}
+
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.designator, &self.number) {
+            (Some(designator), Some(number)) if designator == "#" => {
+                write!(f, "#{number}")
+            }
+            (Some(designator), Some(number)) => write!(f, "{designator} {number}"),
+            (Some(designator), None) => write!(f, "{designator}"),
+            (None, Some(number)) => write!(f, "{number}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// If a token could be a unit number, such as `4B` or `C`.
+fn is_unit_number(token: &str) -> bool {
+    token.len() == 1 || token.bytes().any(|b| b.is_ascii_digit())
+}
+
+/// If an address line is a post office box, such as `P.O. BOX 123` or, in
+/// Canada, `C.P. 123` for case postale.
+fn is_post_office_box(tokens: &[String], country: IssuerCountry) -> bool {
+    let tokens: Vec<String> = tokens.iter().map(|token| token.replace('.', "")).collect();
+    let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+
+    match tokens.as_slice() {
+        ["BOX" | "POBOX", ..]
+        | ["PO", "BOX", ..]
+        | ["P", "O", "BOX", ..]
+        | ["POST", "OFFICE", "BOX", ..] => true,
+        ["CP", ..] | ["C", "P", ..] | ["CASE", "POSTALE", ..] => country == IssuerCountry::Canada,
+        _ => false,
+    }
+}
+
+/// The standard abbreviation for a street suffix.
+fn suffix(token: &str, country: IssuerCountry) -> Option<&'static str> {
+    if country == IssuerCountry::Canada {
+        let suffix = match token {
+            "AVENUE" | "AVE" => "AVE",
+            "BOULEVARD" | "BLVD" => "BLVD",
+            "CIRCLE" | "CIR" => "CIR",
+            "COURT" | "CRT" | "CT" => "CRT",
+            "CRESCENT" | "CRES" => "CRES",
+            "DRIVE" | "DR" => "DR",
+            "GATE" => "GATE",
+            "HEIGHTS" | "HTS" => "HTS",
+            "HIGHWAY" | "HWY" => "HWY",
+            "LANE" => "LANE",
+            "PARKWAY" | "PKY" | "PKWY" => "PKY",
+            "PLACE" | "PL" => "PL",
+            "ROAD" | "RD" => "RD",
+            "STREET" | "ST" => "ST",
+            "TERRACE" | "TERR" => "TERR",
+            "TRAIL" => "TRAIL",
+            "WAY" => "WAY",
+            _ => return None,
+        };
+
+        return Some(suffix);
+    }
+
+    let suffix = match token {
+        "ALLEY" | "ALLY" | "ALY" => "ALY",
+        "AVENUE" | "AV" | "AVE" | "AVEN" | "AVN" | "AVNUE" => "AVE",
+        "BEND" | "BND" => "BND",
+        "BOULEVARD" | "BLVD" | "BOUL" | "BOULV" => "BLVD",
+        "BRANCH" | "BR" | "BRNCH" => "BR",
+        "BRIDGE" | "BRG" | "BRDGE" => "BRG",
+        "BYPASS" | "BYP" | "BYPS" => "BYP",
+        "CANYON" | "CYN" | "CNYN" => "CYN",
+        "CAUSEWAY" | "CSWY" | "CAUSWA" => "CSWY",
+        "CENTER" | "CTR" | "CENTRE" | "CNTR" => "CTR",
+        "CIRCLE" | "CIR" | "CIRC" | "CRCL" => "CIR",
+        "COURT" | "CT" => "CT",
+        "COURTS" | "CTS" => "CTS",
+        "COVE" | "CV" => "CV",
+        "CREEK" | "CRK" => "CRK",
+        "CRESCENT" | "CRES" | "CRSNT" => "CRES",
+        "CROSSING" | "XING" | "CRSSNG" => "XING",
+        "DRIVE" | "DR" | "DRIV" | "DRV" => "DR",
+        "ESTATES" | "ESTS" => "ESTS",
+        "EXPRESSWAY" | "EXPY" | "EXPW" | "EXPR" => "EXPY",
+        "EXTENSION" | "EXT" | "EXTN" => "EXT",
+        "FREEWAY" | "FWY" | "FRWY" => "FWY",
+        "GARDENS" | "GDNS" => "GDNS",
+        "GLEN" | "GLN" => "GLN",
+        "GROVE" | "GRV" => "GRV",
+        "HARBOR" | "HBR" => "HBR",
+        "HEIGHTS" | "HTS" => "HTS",
+        "HIGHWAY" | "HWY" | "HIWAY" => "HWY",
+        "HILL" | "HL" => "HL",
+        "HOLLOW" | "HOLW" => "HOLW",
+        "JUNCTION" | "JCT" => "JCT",
+        "LANDING" | "LNDG" => "LNDG",
+        "LANE" | "LN" => "LN",
+        "LOOP" => "LOOP",
+        "MANOR" | "MNR" => "MNR",
+        "MEADOWS" | "MDWS" => "MDWS",
+        "PARK" => "PARK",
+        "PARKWAY" | "PKWY" | "PKY" | "PARKWY" => "PKWY",
+        "PASS" => "PASS",
+        "PATH" => "PATH",
+        "PIKE" => "PIKE",
+        "PLACE" | "PL" => "PL",
+        "PLAZA" | "PLZ" => "PLZ",
+        "POINT" | "PT" => "PT",
+        "RIDGE" | "RDG" => "RDG",
+        "ROAD" | "RD" => "RD",
+        "ROUTE" | "RTE" => "RTE",
+        "RUN" => "RUN",
+        "SQUARE" | "SQ" => "SQ",
+        "STREET" | "ST" | "STR" | "STRT" => "ST",
+        "TERRACE" | "TER" | "TERR" => "TER",
+        "TRACE" | "TRCE" => "TRCE",
+        "TRAIL" | "TRL" | "TRAILS" | "TRLS" => "TRL",
+        "TURNPIKE" | "TPKE" => "TPKE",
+        "VALLEY" | "VLY" => "VLY",
+        "VIEW" | "VW" => "VW",
+        "VILLAGE" | "VLG" => "VLG",
+        "WALK" => "WALK",
+        "WAY" | "WY" => "WAY",
+        _ => return None,
+    };
+
+    Some(suffix)
+}
+
+/// The standard abbreviation for a street type that comes before the name.
+fn pre_type(token: &str, country: IssuerCountry) -> Option<&'static str> {
+    let pre_type = match token {
+        "HIGHWAY" | "HWY" => "HWY",
+        "ROUTE" | "RTE" => "RTE",
+        "COUNTY" | "CR" if country != IssuerCountry::Canada => "CR",
+        _ if country == IssuerCountry::Canada => match token {
+            "AVENUE" | "AV" => "AV",
+            "BOULEVARD" | "BOUL" => "BOUL",
+            "CHEMIN" | "CH" => "CH",
+            "MONTEE" | "MONTÉE" => "MONTÉE",
+            "PLACE" | "PL" => "PL",
+            "RANG" => "RANG",
+            "ROUTE" | "RTE" => "RTE",
+            "RUE" => "RUE",
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(pre_type)
+}
+
+/// The standard abbreviation for a unit designator and if it must be
+/// followed by a number.
+fn unit_designator(token: &str, country: IssuerCountry) -> Option<(&'static str, bool)> {
+    if country == IssuerCountry::Canada {
+        let designator = match token {
+            "APARTMENT" | "APT" => ("APT", true),
+            "APPARTEMENT" | "APP" => ("APP", true),
+            "BUREAU" => ("BUREAU", true),
+            "SUITE" => ("SUITE", true),
+            "UNIT" | "UNITÉ" => ("UNIT", true),
+            "PENTHOUSE" | "PH" => ("PH", false),
+            _ => return None,
+        };
+
+        return Some(designator);
+    }
+
+    let designator = match token {
+        "APARTMENT" | "APT" => ("APT", true),
+        "BUILDING" | "BLDG" => ("BLDG", true),
+        "DEPARTMENT" | "DEPT" => ("DEPT", true),
+        "FLOOR" | "FL" => ("FL", true),
+        "HANGAR" | "HNGR" => ("HNGR", true),
+        "LOT" => ("LOT", true),
+        "PIER" => ("PIER", true),
+        "ROOM" | "RM" => ("RM", true),
+        "SLIP" => ("SLIP", true),
+        "SPACE" | "SPC" => ("SPC", true),
+        "STOP" => ("STOP", true),
+        "SUITE" | "STE" => ("STE", true),
+        "TRAILER" | "TRLR" => ("TRLR", true),
+        "UNIT" => ("UNIT", true),
+        "BASEMENT" | "BSMT" => ("BSMT", false),
+        "FRONT" | "FRNT" => ("FRNT", false),
+        "LOBBY" | "LBBY" => ("LBBY", false),
+        "LOWER" | "LOWR" => ("LOWR", false),
+        "OFFICE" | "OFC" => ("OFC", false),
+        "PENTHOUSE" | "PH" => ("PH", false),
+        "REAR" => ("REAR", false),
+        "SIDE" => ("SIDE", false),
+        "UPPER" | "UPPR" => ("UPPR", false),
+        _ => return None,
+    };
+
+    Some(designator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::IssuerCountry::*;
+    use crate::tests::{LICENSE_FOLDER, get_test_files, parse_elements};
+
+    #[test]
+    fn test_postal_code() {
+        for entry in get_test_files() {
+            let input = std::fs::read(entry.path()).unwrap();
+            let data = crate::parse_barcode_bytes(&input).unwrap();
+            let postal_code = data.address().unwrap().postal_code;
+            assert!(
+                matches!(postal_code, PostalCode::UnitedStates { .. }),
+                "{postal_code:?}"
+            );
+        }
+
+        let cases = [
+            ("244505700", UnitedStates, "24450-5700"),
+            ("24450570000", UnitedStates, "24450-5700"),
+            ("244500000  ", UnitedStates, "24450"),
+            ("24450-5700", UnitedStates, "24450-5700"),
+            ("24450", UnitedStates, "24450"),
+            ("2445", UnitedStates, "2445"),
+            ("k1a0b1     ", Canada, "K1A 0B1"),
+            ("K1A 0B1", Canada, "K1A 0B1"),
+            ("06600000000", Mexico, "06600"),
+            ("K1A 0B1", UnitedStates, "K1A 0B1"),
+        ];
+
+        for (value, country, expected) in cases {
+            assert_eq!(
+                PostalCode::parse(value, country).to_string(),
+                expected,
+                "{value}"
+            );
+        }
+
+        assert_eq!(
+            PostalCode::parse("K1A 0B1", UnitedStates),
+            PostalCode::Other("K1A 0B1".to_string())
+        );
+
+        // The country in DCG is preferred over the issuer's.
+        let data = parse_elements(
+            636000,
+            10,
+            &[
+                ("DAG", "1 MAIN ST"),
+                ("DAI", "OTTAWA"),
+                ("DAJ", "ON"),
+                ("DAK", "K1A0B1"),
+                ("DCG", "CAN"),
+            ],
+        );
+        assert_eq!(
+            data.address().unwrap().postal_code,
+            PostalCode::Canada("K1A 0B1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_street_address() {
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-21.txt")).unwrap();
+        let data = crate::parse_barcode_bytes(&input).unwrap();
+        let street_address = data.street_address().unwrap();
+        assert_eq!(
+            street_address,
+            StreetAddress {
+                house_number: Some("110".to_string()),
+                pre_directional: Some(Directional::East),
+                street_name: "MIDLAND".to_string(),
+                suffix: Some("TRL".to_string()),
+                ..Default::default()
+            }
+        );
+
+        let cases = [
+            ("110 East Midland Trail", UnitedStates, "110 E MIDLAND TRL"),
+            ("5235 JOHN TYLER HWY", UnitedStates, "5235 JOHN TYLER HWY"),
+            ("123 NORTH ST", UnitedStates, "123 NORTH ST"),
+            (
+                "123 MAIN STREET NW APT 4B",
+                UnitedStates,
+                "123 MAIN ST NW APT 4B",
+            ),
+            ("123 MAIN ST. #4", UnitedStates, "123 MAIN ST #4"),
+            ("123 MAIN APT 4", UnitedStates, "123 MAIN APT 4"),
+            (
+                "110 1/2 OAK AVENUE REAR",
+                UnitedStates,
+                "110 1/2 OAK AVE REAR",
+            ),
+            ("4000 HIGHWAY 52", UnitedStates, "4000 HWY 52"),
+            ("100 COUNTY ROAD 5", UnitedStates, "100 CR 5"),
+            ("12-345 MAIN STREET WEST", Canada, "12-345 MAIN ST W"),
+            (
+                "1234 RUE SAINTE-CATHERINE O",
+                Canada,
+                "1234 RUE SAINTE-CATHERINE W",
+            ),
+            (
+                "55 BOULEVARD RENE-LEVESQUE",
+                Canada,
+                "55 BOUL RENE-LEVESQUE",
+            ),
+            ("200 BAY ST SUITE 300", Canada, "200 BAY ST SUITE 300"),
+        ];
+
+        for (line, country, expected) in cases {
+            let street_address = StreetAddress::parse(line, country).unwrap();
+            assert_eq!(street_address.to_string(), expected, "{line}");
+        }
+
+        // Unit designators that are part of the street name.
+        let cases = [
+            ("45 N PIER RD", "PIER"),
+            ("100 W LOWER RIVER RD", "LOWER RIVER"),
+        ];
+
+        for (line, street_name) in cases {
+            let street_address = StreetAddress::parse(line, UnitedStates).unwrap();
+            assert_eq!(street_address.street_name, street_name, "{line}");
+            assert_eq!(street_address.suffix.as_deref(), Some("RD"), "{line}");
+            assert_eq!(street_address.unit, None, "{line}");
+        }
+
+        let street_address = StreetAddress::parse("12-345 MAIN ST", Canada).unwrap();
+        assert_eq!(street_address.house_number.as_deref(), Some("345"));
+        assert_eq!(
+            street_address.unit,
+            Some(Unit {
+                designator: None,
+                number: Some("12".to_string()),
+            })
+        );
+        assert_eq!(
+            StreetAddress::parse("12-345 MAIN ST", UnitedStates)
+                .unwrap()
+                .house_number
+                .as_deref(),
+            Some("12-345")
+        );
+
+        assert_eq!(StreetAddress::parse("", UnitedStates), None);
+
+        let data = parse_elements(636000, 10, &[("DAG", "1 MAIN ST"), ("DAH", "SUITE 200")]);
+        assert_eq!(
+            data.street_address().unwrap().to_string(),
+            "1 MAIN ST STE 200"
+        );
+    }
+
+    #[test]
+    fn test_post_office_box() {
+        let cases = [
+            ("PO BOX 123", UnitedStates),
+            ("P.O. BOX 123", UnitedStates),
+            ("P. O. BOX 123", UnitedStates),
+            ("POST OFFICE BOX 123", UnitedStates),
+            ("BOX 123", UnitedStates),
+            ("C.P. 123", Canada),
+            ("CASE POSTALE 123", Canada),
+        ];
+
+        for (line, country) in cases {
+            assert_eq!(StreetAddress::parse(line, country), None, "{line}");
+        }
+
+        let cases = [
+            ("BOXWOOD LN", UnitedStates, "BOXWOOD LN"),
+            ("CP RAIL RD", UnitedStates, "CP RAIL RD"),
+            ("POBOXER ST", UnitedStates, "POBOXER ST"),
+        ];
+
+        for (line, country, expected) in cases {
+            let street_address = StreetAddress::parse(line, country).unwrap();
+            assert_eq!(street_address.to_string(), expected, "{line}");
+        }
+    }
+}
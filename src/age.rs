@@ -0,0 +1,198 @@
+//! Checking how old a cardholder is.
+
+use jiff::civil::Date;
+use serde::Serialize;
+
+use crate::{DecodedData, clock::Clock};
+
+/// The result of an age check and what it was based on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AgeCheck<T> {
+    pub value: T,
+    pub source: AgeSource,
+    /// If the date of birth and the issuer's under age until dates do not
+    /// agree with each other.
+    pub disagreement: bool,
+}
+
+/// Where the result of an age check came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AgeSource {
+    /// One of the issuer's under 18, 19, or 21 until dates.
+    UnderAgeUntil,
+    /// A calculation from the date of birth.
+    DateOfBirth,
+    /// A calculation from the latest possible date of birth, as its month or
+    /// day was unknown.
+    PartialDateOfBirth,
+}
+
+/// The date someone born on `date_of_birth` turns `years` old.
+///
+/// People born on February 29 are treated as turning a year older on March 1
+/// in years without a February 29.
+pub fn birthday(date_of_birth: Date, years: i16) -> Option<Date> {
+    let year = date_of_birth.year().checked_add(years)?;
+
+    Date::new(year, date_of_birth.month(), date_of_birth.day())
+        .or_else(|_| Date::new(year, 3, 1))
+        .ok()
+}
+
+/// How many full years old someone born on `date_of_birth` is on `on`.
+pub fn age_on(date_of_birth: Date, on: Date) -> Option<i16> {
+    let years = on.year() - date_of_birth.year();
+
+    if on >= birthday(date_of_birth, years)? {
+        Some(years)
+    } else {
+        Some(years - 1)
+    }
+}
+
+impl DecodedData {
+    /// How many full years old the cardholder is on a date, calculated from
+    /// their date of birth.
+    ///
+    /// When only part of the date of birth is known, the latest date it could
+    /// be is used so the cardholder is never thought to be older than they
+    /// are.
+    pub fn age_on(&self, on: Date) -> Option<AgeCheck<i16>> {
+        let (date_of_birth, source) = self.latest_date_of_birth()?;
+
+        Some(AgeCheck {
+            value: age_on(date_of_birth, on)?,
+            source,
+            disagreement: self.age_disagreement(),
+        })
+    }
+
+    /// How many full years old the cardholder is today.
+    pub fn age_today(&self, clock: &impl Clock) -> Option<AgeCheck<i16>> {
+        self.age_on(clock.today())
+    }
+
+    /// If the cardholder is at least some number of years old on a date.
+    ///
+    /// The issuer's under age until date is used when there is one for that
+    /// age, otherwise this is calculated from the date of birth as in
+    /// [`DecodedData::age_on`].
+    pub fn is_at_least(&self, years: i16, on: Date) -> Option<AgeCheck<bool>> {
+        let disagreement = self.age_disagreement();
+
+        if let Some(until) = self.under_age_until(years) {
+            return Some(AgeCheck {
+                value: on >= until,
+                source: AgeSource::UnderAgeUntil,
+                disagreement,
+            });
+        }
+
+        let (date_of_birth, source) = self.latest_date_of_birth()?;
+
+        Some(AgeCheck {
+            value: on >= birthday(date_of_birth, years)?,
+            source,
+            disagreement,
+        })
+    }
+
+    /// If the cardholder is at least some number of years old today.
+    pub fn is_at_least_today(&self, years: i16, clock: &impl Clock) -> Option<AgeCheck<bool>> {
+        self.is_at_least(years, clock.today())
+    }
+
+    /// The date of birth, or the latest it could be if only part of it is
+    /// known.
+    fn latest_date_of_birth(&self) -> Option<(Date, AgeSource)> {
+        if let Some(date_of_birth) = self.date_of_birth {
+            return Some((date_of_birth, AgeSource::DateOfBirth));
+        }
+
+        let date_of_birth = self.partial_date_of_birth?.latest()?;
+
+        Some((date_of_birth, AgeSource::PartialDateOfBirth))
+    }
+
+    fn under_age_until(&self, years: i16) -> Option<Date> {
+        match years {
+            18 => self.under_age_until.under_18_until,
+            19 => self.under_age_until.under_19_until,
+            21 => self.under_age_until.under_21_until,
+            _ => None,
+        }
+    }
+
+    /// If any under age until date is not the birthday it should be.
+    fn age_disagreement(&self) -> bool {
+        let Some(date_of_birth) = self.date_of_birth else {
+            return false;
+        };
+
+        [18, 19, 21].into_iter().any(|years| {
+            self.under_age_until(years)
+                .is_some_and(|until| Some(until) != birthday(date_of_birth, years))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::tests::{LICENSE_FOLDER, parse_elements};
+
+    #[test]
+    fn test_age() {
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-REAL-U21.txt")).unwrap();
+        let decoded = DecodedData::from(crate::parse_barcode_bytes(&input).unwrap());
+
+        let check = decoded.is_at_least(21, date(2027, 4, 9)).unwrap();
+        assert!(!check.value);
+        assert_eq!(check.source, AgeSource::DateOfBirth);
+        assert!(!check.disagreement);
+        assert!(decoded.is_at_least(21, date(2027, 4, 10)).unwrap().value);
+        assert_eq!(
+            decoded
+                .age_today(&FixedClock::on(date(2027, 4, 10)))
+                .unwrap()
+                .value,
+            21
+        );
+
+        // Birthdays on February 29 are March 1 in other years.
+        assert_eq!(birthday(date(2004, 2, 29), 21), Some(date(2025, 3, 1)));
+        assert_eq!(birthday(date(2004, 2, 29), 20), Some(date(2024, 2, 29)));
+        assert_eq!(age_on(date(2004, 2, 29), date(2025, 2, 28)), Some(20));
+        assert_eq!(age_on(date(2004, 2, 29), date(2025, 3, 1)), Some(21));
+
+        let data = parse_elements(
+            636000,
+            10,
+            &[
+                ("DBB", "02292004"),
+                ("DDH", "03012022"),
+                ("DDJ", "03022025"),
+            ],
+        );
+        let decoded = DecodedData::from(data);
+
+        let check = decoded
+            .is_at_least_today(21, &FixedClock::on(date(2025, 3, 1)))
+            .unwrap();
+        assert!(!check.value);
+        assert_eq!(check.source, AgeSource::UnderAgeUntil);
+        assert!(check.disagreement);
+
+        let check = decoded.is_at_least(18, date(2022, 3, 1)).unwrap();
+        assert!(check.value);
+        assert_eq!(check.source, AgeSource::UnderAgeUntil);
+
+        let check = decoded.is_at_least(25, date(2029, 3, 1)).unwrap();
+        assert!(check.value);
+        assert_eq!(check.source, AgeSource::DateOfBirth);
+    }
+}
@@ -0,0 +1,49 @@
+//! Sources of the current time, so checks that depend on it can be tested.
+
+use jiff::{Zoned, civil::Date, tz::TimeZone};
+
+/// Something that knows the current time.
+pub trait Clock {
+    fn now(&self) -> Zoned;
+
+    /// The current date in the clock's time zone.
+    fn today(&self) -> Date {
+        self.now().date()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> Zoned {
+        (**self).now()
+    }
+}
+
+/// The system's clock, in the system's time zone.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Zoned {
+        Zoned::now()
+    }
+}
+
+/// A clock that is always at the same time.
+#[derive(Debug, Clone)]
+pub struct FixedClock(pub Zoned);
+
+impl FixedClock {
+    /// A clock at the start of a day in UTC.
+    pub fn on(date: Date) -> Self {
+        Self(
+            date.to_zoned(TimeZone::UTC)
+                .expect("start of day in utc should always exist"),
+        )
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> Zoned {
+        self.0.clone()
+    }
+}
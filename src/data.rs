@@ -1,4 +1,4 @@
-use std::ops::Not;
+use std::ops::{Not, RangeInclusive};
 
 use itertools::Itertools;
 use jiff::civil::Date;
@@ -6,7 +6,11 @@ use num_enum::{IntoPrimitive, TryFromPrimitive};
 use serde::{Deserialize, Serialize};
 use tap::TapOptional;
 
-use crate::{Data, SubfileType};
+use crate::{
+    Data, Diagnostic, SubfileType,
+    address::{PostalCode, StreetAddress, Unit},
+    elements::{ElementId, FieldKind},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[cfg_attr(feature = "web", derive(tsify::Tsify))]
@@ -107,6 +111,46 @@ impl IssuerIdentification {
             _ => IssuerCountry::UnitedStates,
         }
     }
+
+    /// The IANA time zone of the westernmost part of the jurisdiction, where
+    /// each day ends last.
+    pub fn time_zone(&self) -> &'static str {
+        use IssuerIdentification::*;
+
+        match self {
+            Alabama | Arkansas | Florida | Illinois | Indiana | Iowa | Kentucky | Louisiana
+            | Minnesota | Mississippi | Missouri | Oklahoma | Tennessee | Wisconsin => {
+                "America/Chicago"
+            }
+            Connecticut | Delaware | DistrictOfColumbia | Georgia | Maine | Maryland
+            | Massachusetts | NewHampshire | NewJersey | NewYork | NorthCarolina | Ohio
+            | Pennsylvania | RhodeIsland | SouthCarolina | StateDepartment | Vermont | Virginia
+            | WestVirginia => "America/New_York",
+            Colorado | Kansas | Montana | Nebraska | NewMexico | NorthDakota | SouthDakota
+            | Texas | Utah | Wyoming => "America/Denver",
+            California | Idaho | Nevada | Oregon | Washington => "America/Los_Angeles",
+            Alaska => "America/Adak",
+            AmericanSamoa => "Pacific/Pago_Pago",
+            Arizona => "America/Phoenix",
+            Guam => "Pacific/Guam",
+            Hawaii => "Pacific/Honolulu",
+            Michigan => "America/Menominee",
+            NortherMariannaIslands => "Pacific/Saipan",
+            PuertoRico => "America/Puerto_Rico",
+            VirginIslands => "America/St_Thomas",
+            Alberta | NorthwestTerritories | Saskatchewan => "America/Edmonton",
+            BritishColumbia => "America/Vancouver",
+            Manitoba | Ontario => "America/Winnipeg",
+            NewBrunswick => "America/Moncton",
+            Newfoundland => "America/Goose_Bay",
+            NovaScotia | PrinceEdwardIsland => "America/Halifax",
+            Nunavut => "America/Cambridge_Bay",
+            Quebec => "America/Toronto",
+            Yukon => "America/Whitehorse",
+            Coahuila => "America/Monterrey",
+            Hidalgo => "America/Mexico_City",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -118,15 +162,21 @@ pub struct DecodedData {
     pub jurisdiction_version: Option<u8>,
     #[cfg_attr(feature = "web", tsify(type = "string | undefined"))]
     pub document_expiration_date: Option<Date>,
+    pub expiration: Expiration,
     pub name: Option<Name>,
     #[cfg_attr(feature = "web", tsify(type = "string | undefined"))]
     pub document_issue_date: Option<Date>,
     #[cfg_attr(feature = "web", tsify(type = "string | undefined"))]
     pub date_of_birth: Option<Date>,
+    pub partial_date_of_birth: Option<PartialDate>,
     pub sex: Option<Sex>,
     pub eye_color: Option<EyeColor>,
     pub height: Option<Height>,
+    /// The mailing address, which is the only address in versions after 1.
     pub address: Option<Address>,
+    /// The residence address, when it was given separately from the mailing
+    /// address.
+    pub residence_address: Option<Address>,
     pub customer_id_number: Option<String>,
     pub document_discriminator: Option<String>,
     pub country: Option<IssuerCountry>,
@@ -140,6 +190,12 @@ pub struct DecodedData {
     pub card_revision_date: Option<Date>,
     #[serde(skip_serializing_if = "UnderAgeUntil::is_empty")]
     pub under_age_until: UnderAgeUntil,
+    pub compliance_type: Option<ComplianceType>,
+    pub organ_donor: Option<bool>,
+    pub veteran: Option<bool>,
+    pub limited_duration_document: Option<bool>,
+    pub privileges: Option<Privileges>,
+    pub commercial: Commercial,
 }
 
 impl From<Data<'_>> for DecodedData {
@@ -150,12 +206,15 @@ impl From<Data<'_>> for DecodedData {
             jurisdiction_version: value.header.jurisdiction_version_number,
             name: value.name(),
             document_expiration_date: value.document_expiration_date(),
+            expiration: value.expiration(),
             date_of_birth: value.date_of_birth(),
+            partial_date_of_birth: value.partial_date_of_birth(),
             document_issue_date: value.document_issue_date(),
             sex: value.sex(),
             eye_color: value.eye_color(),
             height: value.height(),
             address: value.address(),
+            residence_address: value.residence_address(),
             customer_id_number: value.customer_id_number(),
             document_discriminator: value.document_discriminator(),
             country: value.country(),
@@ -167,6 +226,12 @@ impl From<Data<'_>> for DecodedData {
             race: value.race(),
             card_revision_date: value.card_revision_date(),
             under_age_until: value.under_age_until(),
+            compliance_type: value.compliance_type(),
+            organ_donor: value.organ_donor(),
+            veteran: value.veteran(),
+            limited_duration_document: value.limited_duration_document(),
+            privileges: value.privileges(),
+            commercial: value.commercial(),
         }
     }
 }
@@ -215,7 +280,7 @@ pub struct Address {
     pub address_2: Option<String>,
     pub city: String,
     pub jurisdiction_code: String,
-    pub postal_code: String,
+    pub postal_code: PostalCode,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -279,6 +344,508 @@ pub enum Race {
     White,
 }
 
+/// A date that may be missing its month or day, as dates of birth are for
+/// some cardholders born outside the country.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct PartialDate {
+    pub year: i16,
+    pub month: Option<i8>,
+    /// The day, which is only known if the month is.
+    pub day: Option<i8>,
+}
+
+impl PartialDate {
+    /// Create a date from its parts, where a month or day of `0` is unknown.
+    pub fn new(year: i16, month: i8, day: i8) -> Option<Self> {
+        let month = Some(month).filter(|month| *month != 0);
+        let day = Some(day).filter(|day| *day != 0 && month.is_some());
+
+        let date = Self { year, month, day };
+        date.earliest()?;
+
+        Some(date)
+    }
+
+    /// The full date, if every part of it is known.
+    pub fn to_date(&self) -> Option<Date> {
+        Date::new(self.year, self.month?, self.day?).ok()
+    }
+
+    /// The earliest date this could be.
+    pub fn earliest(&self) -> Option<Date> {
+        Date::new(self.year, self.month.unwrap_or(1), self.day.unwrap_or(1)).ok()
+    }
+
+    /// The latest date this could be.
+    pub fn latest(&self) -> Option<Date> {
+        match (self.month, self.day) {
+            (Some(month), Some(day)) => Date::new(self.year, month, day).ok(),
+            (Some(month), None) => Some(Date::new(self.year, month, 1).ok()?.last_of_month()),
+            (None, _) => Date::new(self.year, 12, 31).ok(),
+        }
+    }
+}
+
+impl From<Date> for PartialDate {
+    fn from(date: Date) -> Self {
+        Self {
+            year: date.year(),
+            month: Some(date.month()),
+            day: Some(date.day()),
+        }
+    }
+}
+
+impl std::fmt::Display for PartialDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.year)?;
+
+        if let Some(month) = self.month {
+            write!(f, "-{month:02}")?;
+        }
+
+        if let Some(day) = self.day {
+            write!(f, "-{day:02}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The order of the parts of dates in a barcode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum DateFormat {
+    /// `MMDDCCYY`, used in the United States after version 1.
+    MonthDayYear,
+    /// `CCYYMMDD`, used in Canada, Mexico, and version 1.
+    YearMonthDay,
+}
+
+/// Years a date on a card is expected to fall in. A date outside them was
+/// probably read in the wrong format.
+const PLAUSIBLE_YEARS: RangeInclusive<i16> = 1900..=2100;
+
+/// The date format each issuer uses in each version of the standard.
+///
+/// Every issuer starts out with the format the standard gives for its
+/// country: `CCYYMMDD` in version 1 and outside the United States, and
+/// `MMDDCCYY` in the United States after that. An issuer that writes dates
+/// another way is pinned by changing its rows. Issuers and versions without a
+/// row use the rule for their country.
+pub(crate) static ISSUER_DATE_FORMATS: &[(IssuerIdentification, RangeInclusive<u8>, DateFormat)] = {
+    use DateFormat::*;
+    use IssuerIdentification as I;
+
+    &[
+        (I::Alabama, 1..=1, YearMonthDay),
+        (I::Alabama, 2..=10, MonthDayYear),
+        (I::Alaska, 1..=1, YearMonthDay),
+        (I::Alaska, 2..=10, MonthDayYear),
+        (I::Alberta, 1..=10, YearMonthDay),
+        (I::AmericanSamoa, 1..=1, YearMonthDay),
+        (I::AmericanSamoa, 2..=10, MonthDayYear),
+        (I::Arizona, 1..=1, YearMonthDay),
+        (I::Arizona, 2..=10, MonthDayYear),
+        (I::Arkansas, 1..=1, YearMonthDay),
+        (I::Arkansas, 2..=10, MonthDayYear),
+        (I::BritishColumbia, 1..=10, YearMonthDay),
+        (I::California, 1..=1, YearMonthDay),
+        (I::California, 2..=10, MonthDayYear),
+        (I::Coahuila, 1..=10, YearMonthDay),
+        (I::Colorado, 1..=1, YearMonthDay),
+        (I::Colorado, 2..=10, MonthDayYear),
+        (I::Connecticut, 1..=1, YearMonthDay),
+        (I::Connecticut, 2..=10, MonthDayYear),
+        (I::Delaware, 1..=1, YearMonthDay),
+        (I::Delaware, 2..=10, MonthDayYear),
+        (I::DistrictOfColumbia, 1..=1, YearMonthDay),
+        (I::DistrictOfColumbia, 2..=10, MonthDayYear),
+        (I::Florida, 1..=1, YearMonthDay),
+        (I::Florida, 2..=10, MonthDayYear),
+        (I::Georgia, 1..=1, YearMonthDay),
+        (I::Georgia, 2..=10, MonthDayYear),
+        (I::Guam, 1..=1, YearMonthDay),
+        (I::Guam, 2..=10, MonthDayYear),
+        (I::Hawaii, 1..=1, YearMonthDay),
+        (I::Hawaii, 2..=10, MonthDayYear),
+        (I::Hidalgo, 1..=10, YearMonthDay),
+        (I::Idaho, 1..=1, YearMonthDay),
+        (I::Idaho, 2..=10, MonthDayYear),
+        (I::Illinois, 1..=1, YearMonthDay),
+        (I::Illinois, 2..=10, MonthDayYear),
+        (I::Indiana, 1..=1, YearMonthDay),
+        (I::Indiana, 2..=10, MonthDayYear),
+        (I::Iowa, 1..=1, YearMonthDay),
+        (I::Iowa, 2..=10, MonthDayYear),
+        (I::Kansas, 1..=1, YearMonthDay),
+        (I::Kansas, 2..=10, MonthDayYear),
+        (I::Kentucky, 1..=1, YearMonthDay),
+        (I::Kentucky, 2..=10, MonthDayYear),
+        (I::Louisiana, 1..=1, YearMonthDay),
+        (I::Louisiana, 2..=10, MonthDayYear),
+        (I::Maine, 1..=1, YearMonthDay),
+        (I::Maine, 2..=10, MonthDayYear),
+        (I::Manitoba, 1..=10, YearMonthDay),
+        (I::Maryland, 1..=1, YearMonthDay),
+        (I::Maryland, 2..=10, MonthDayYear),
+        (I::Massachusetts, 1..=1, YearMonthDay),
+        (I::Massachusetts, 2..=10, MonthDayYear),
+        (I::Michigan, 1..=1, YearMonthDay),
+        (I::Michigan, 2..=10, MonthDayYear),
+        (I::Minnesota, 1..=1, YearMonthDay),
+        (I::Minnesota, 2..=10, MonthDayYear),
+        (I::Mississippi, 1..=1, YearMonthDay),
+        (I::Mississippi, 2..=10, MonthDayYear),
+        (I::Missouri, 1..=1, YearMonthDay),
+        (I::Missouri, 2..=10, MonthDayYear),
+        (I::Montana, 1..=1, YearMonthDay),
+        (I::Montana, 2..=10, MonthDayYear),
+        (I::Nebraska, 1..=1, YearMonthDay),
+        (I::Nebraska, 2..=10, MonthDayYear),
+        (I::Nevada, 1..=1, YearMonthDay),
+        (I::Nevada, 2..=10, MonthDayYear),
+        (I::NewBrunswick, 1..=10, YearMonthDay),
+        (I::Newfoundland, 1..=10, YearMonthDay),
+        (I::NewHampshire, 1..=1, YearMonthDay),
+        (I::NewHampshire, 2..=10, MonthDayYear),
+        (I::NewJersey, 1..=1, YearMonthDay),
+        (I::NewJersey, 2..=10, MonthDayYear),
+        (I::NewMexico, 1..=1, YearMonthDay),
+        (I::NewMexico, 2..=10, MonthDayYear),
+        (I::NewYork, 1..=1, YearMonthDay),
+        (I::NewYork, 2..=10, MonthDayYear),
+        (I::NorthCarolina, 1..=1, YearMonthDay),
+        (I::NorthCarolina, 2..=10, MonthDayYear),
+        (I::NorthDakota, 1..=1, YearMonthDay),
+        (I::NorthDakota, 2..=10, MonthDayYear),
+        (I::NortherMariannaIslands, 1..=1, YearMonthDay),
+        (I::NortherMariannaIslands, 2..=10, MonthDayYear),
+        (I::NorthwestTerritories, 1..=10, YearMonthDay),
+        (I::NovaScotia, 1..=10, YearMonthDay),
+        (I::Nunavut, 1..=10, YearMonthDay),
+        (I::Ohio, 1..=1, YearMonthDay),
+        (I::Ohio, 2..=10, MonthDayYear),
+        (I::Oklahoma, 1..=1, YearMonthDay),
+        (I::Oklahoma, 2..=10, MonthDayYear),
+        (I::Ontario, 1..=10, YearMonthDay),
+        (I::Oregon, 1..=1, YearMonthDay),
+        (I::Oregon, 2..=10, MonthDayYear),
+        (I::Pennsylvania, 1..=1, YearMonthDay),
+        (I::Pennsylvania, 2..=10, MonthDayYear),
+        (I::PrinceEdwardIsland, 1..=10, YearMonthDay),
+        (I::PuertoRico, 1..=1, YearMonthDay),
+        (I::PuertoRico, 2..=10, MonthDayYear),
+        (I::Quebec, 1..=10, YearMonthDay),
+        (I::RhodeIsland, 1..=1, YearMonthDay),
+        (I::RhodeIsland, 2..=10, MonthDayYear),
+        (I::Saskatchewan, 1..=10, YearMonthDay),
+        (I::SouthCarolina, 1..=1, YearMonthDay),
+        (I::SouthCarolina, 2..=10, MonthDayYear),
+        (I::SouthDakota, 1..=1, YearMonthDay),
+        (I::SouthDakota, 2..=10, MonthDayYear),
+        (I::StateDepartment, 1..=1, YearMonthDay),
+        (I::StateDepartment, 2..=10, MonthDayYear),
+        (I::Tennessee, 1..=1, YearMonthDay),
+        (I::Tennessee, 2..=10, MonthDayYear),
+        (I::Texas, 1..=1, YearMonthDay),
+        (I::Texas, 2..=10, MonthDayYear),
+        (I::Utah, 1..=1, YearMonthDay),
+        (I::Utah, 2..=10, MonthDayYear),
+        (I::Vermont, 1..=1, YearMonthDay),
+        (I::Vermont, 2..=10, MonthDayYear),
+        (I::Virginia, 1..=1, YearMonthDay),
+        (I::Virginia, 2..=10, MonthDayYear),
+        (I::VirginIslands, 1..=1, YearMonthDay),
+        (I::VirginIslands, 2..=10, MonthDayYear),
+        (I::Washington, 1..=1, YearMonthDay),
+        (I::Washington, 2..=10, MonthDayYear),
+        (I::WestVirginia, 1..=1, YearMonthDay),
+        (I::WestVirginia, 2..=10, MonthDayYear),
+        (I::Wisconsin, 1..=1, YearMonthDay),
+        (I::Wisconsin, 2..=10, MonthDayYear),
+        (I::Wyoming, 1..=1, YearMonthDay),
+        (I::Wyoming, 2..=10, MonthDayYear),
+        (I::Yukon, 1..=10, YearMonthDay),
+    ]
+};
+
+impl DateFormat {
+    /// The format an issuer uses for dates in a version of the standard, from
+    /// its row in the issuer table or otherwise the rule for its country.
+    ///
+    /// Documents that do not follow their issuer's format can be read by
+    /// choosing the format in [`ParseOptions`].
+    pub fn for_issuer(issuer_id: u32, version: u8) -> Self {
+        let issuer = IssuerIdentification::try_from(issuer_id).ok();
+
+        let pinned = ISSUER_DATE_FORMATS
+            .iter()
+            .find(|(id, versions, _)| Some(*id) == issuer && versions.contains(&version))
+            .map(|(_, _, format)| *format);
+
+        pinned.unwrap_or_else(|| {
+            Self::for_country(
+                issuer.map(|issuer| issuer.country()).unwrap_or_default(),
+                version,
+            )
+        })
+    }
+
+    /// The format the standard gives for dates from a country in a version of
+    /// the standard.
+    fn for_country(country: IssuerCountry, version: u8) -> Self {
+        if country == IssuerCountry::UnitedStates && version != 1 {
+            Self::MonthDayYear
+        } else {
+            Self::YearMonthDay
+        }
+    }
+
+    fn other(self) -> Self {
+        match self {
+            Self::MonthDayYear => Self::YearMonthDay,
+            Self::YearMonthDay => Self::MonthDayYear,
+        }
+    }
+
+    /// Split an eight character date into its year, month, and day.
+    fn split(self, input: &str) -> Option<(&str, &str, &str)> {
+        if input.len() != 8 || !input.is_ascii() {
+            return None;
+        }
+
+        let parts = match self {
+            Self::MonthDayYear => (&input[4..], &input[..2], &input[2..4]),
+            Self::YearMonthDay => (&input[..4], &input[4..6], &input[6..]),
+        };
+
+        Some(parts)
+    }
+
+    fn parse(self, input: &str) -> Option<Date> {
+        let (year, month, day) = self.split(input)?;
+
+        Date::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?).ok()
+    }
+
+    fn parse_partial(self, input: &str) -> Option<PartialDate> {
+        let (year, month, day) = self.split(input)?;
+
+        PartialDate::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Write a date in this format, with unknown parts as `0`.
+    pub(crate) fn format(self, year: i16, month: i8, day: i8) -> String {
+        match self {
+            Self::MonthDayYear => format!("{month:02}{day:02}{year:04}"),
+            Self::YearMonthDay => format!("{year:04}{month:02}{day:02}"),
+        }
+    }
+}
+
+/// A date and how it was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ResolvedDate {
+    pub date: Date,
+    /// The format the date was read in.
+    pub format: DateFormat,
+    pub resolution: DateResolution,
+    /// If no format gave a year in a plausible range, so the date may have
+    /// been read in the wrong format.
+    pub ambiguous: bool,
+}
+
+/// Why a date was read in the format it was.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateResolution {
+    /// The format was chosen in the parse options.
+    Override,
+    /// The format is the one the issuer uses in this version.
+    Issuer,
+    /// The value was not a valid date in the issuer's format but was in the
+    /// other one.
+    Fallback,
+}
+
+/// When a document expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum Expiration {
+    /// The document expires at the end of this day.
+    On(#[cfg_attr(feature = "web", tsify(type = "string"))] Date),
+    /// The document never expires, as some IDs issued to seniors do.
+    NonExpiring,
+    /// The expiration date was missing or could not be parsed.
+    Unknown,
+}
+
+/// If a document meets the requirements of the REAL ID Act.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum ComplianceType {
+    /// `F`, the document is REAL ID compliant.
+    FullyCompliant,
+    /// `N`, the document is not REAL ID compliant.
+    NonCompliant,
+    /// The document was issued under a version of the standard from before
+    /// compliance was recorded.
+    Legacy,
+    /// A value not defined by the standard.
+    Other(String),
+}
+
+/// The vehicles a document allows driving and the conditions attached to it.
+///
+/// Codes are listed as the jurisdiction printed them. When a jurisdiction
+/// uses its own codes, the standard codes with the same meaning may be given
+/// separately.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct Privileges {
+    pub vehicle_classes: Vec<VehicleClass>,
+    pub restrictions: Vec<Restriction>,
+    pub endorsements: Vec<Endorsement>,
+
+    pub standard_vehicle_classes: Vec<VehicleClass>,
+    pub standard_restrictions: Vec<Restriction>,
+    pub standard_endorsements: Vec<Endorsement>,
+
+    pub vehicle_class_description: Option<String>,
+    pub restrictions_description: Option<String>,
+    pub endorsements_description: Option<String>,
+}
+
+impl Privileges {
+    /// If the document allows driving a class of vehicle, either by its own
+    /// code or by a standard equivalent.
+    pub fn has_vehicle_class(&self, vehicle_class: &VehicleClass) -> bool {
+        self.vehicle_classes.contains(vehicle_class)
+            || self.standard_vehicle_classes.contains(vehicle_class)
+    }
+
+    /// If the document has an endorsement, either by its own code or by a
+    /// standard equivalent.
+    pub fn has_endorsement(&self, endorsement: &Endorsement) -> bool {
+        self.endorsements.contains(endorsement) || self.standard_endorsements.contains(endorsement)
+    }
+
+    /// If the document has a restriction, either by its own code or by a
+    /// standard equivalent.
+    pub fn has_restriction(&self, restriction: &Restriction) -> bool {
+        self.restrictions.contains(restriction) || self.standard_restrictions.contains(restriction)
+    }
+}
+
+/// Details about commercial driver's licenses.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct Commercial {
+    /// If the document appears to be a commercial driver's license.
+    ///
+    /// This is true when it has federal commercial vehicle codes, a hazmat
+    /// endorsement, or a standard class A, B, or C in `DCM`. Jurisdiction
+    /// classes are not used, as some jurisdictions reuse these letters for
+    /// non-commercial classes.
+    pub is_commercial: bool,
+    pub federal_commercial_vehicle_codes: Option<String>,
+    /// When the hazardous materials endorsement expires, if the holder has
+    /// one.
+    #[cfg_attr(feature = "web", tsify(type = "string | undefined"))]
+    pub hazmat_endorsement_expiration_date: Option<Date>,
+}
+
+/// A class of vehicle a document allows driving.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum VehicleClass {
+    /// `A`, combination vehicles over 26,001 pounds towing more than 10,000
+    /// pounds.
+    A,
+    /// `B`, single vehicles over 26,001 pounds.
+    B,
+    /// `C`, smaller vehicles carrying passengers or hazardous materials.
+    C,
+    /// `M`, motorcycles.
+    M,
+    /// A class defined by the jurisdiction.
+    Other(String),
+}
+
+/// A restriction on what or how a holder may drive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum Restriction {
+    /// `B`
+    CorrectiveLenses,
+    /// `C`
+    MechanicalAid,
+    /// `D`
+    ProstheticAid,
+    /// `E`, no manual transmission commercial vehicles.
+    AutomaticTransmission,
+    /// `F`
+    OutsideMirror,
+    /// `G`
+    DaylightOnly,
+    /// `H`
+    EmploymentOnly,
+    /// `I`, limited in some other way.
+    LimitedOther,
+    /// `J`, any other restriction.
+    OtherRestriction,
+    /// `K`
+    IntrastateOnly,
+    /// `L`, no commercial vehicles with air brakes.
+    NoAirBrakes,
+    /// `M`, no class A passenger vehicles.
+    NoClassAPassengerVehicles,
+    /// `N`, no class A or B passenger vehicles.
+    NoClassAOrBPassengerVehicles,
+    /// `O`, no tractor-trailer commercial vehicles.
+    NoTractorTrailer,
+    /// `P`, no passengers in commercial buses.
+    NoPassengers,
+    /// `V`
+    MedicalVariance,
+    /// `W`
+    FarmWaiver,
+    /// `X`, commercial tank vehicles must be empty.
+    EmptyTankVehiclesOnly,
+    /// `Z`, no commercial vehicles with full air brakes.
+    NoFullAirBrakes,
+    /// A restriction defined by the jurisdiction.
+    Other(String),
+}
+
+/// An endorsement allowing the holder to drive a kind of vehicle or cargo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+#[serde(rename_all = "snake_case")]
+pub enum Endorsement {
+    /// `H`
+    HazardousMaterials,
+    /// `N`
+    TankVehicle,
+    /// `P`
+    PassengerVehicle,
+    /// `S`
+    SchoolBus,
+    /// `T`
+    DoubleTripleTrailers,
+    /// `X`, tank vehicles carrying hazardous materials.
+    TankVehicleHazardousMaterials,
+    /// An endorsement defined by the jurisdiction.
+    Other(String),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "web", derive(tsify::Tsify))]
 pub struct UnderAgeUntil {
@@ -298,6 +865,34 @@ impl UnderAgeUntil {
     }
 }
 
+/// The value of an element and where it was found.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Field<'d> {
+    pub subfile_type: SubfileType,
+    pub id: ElementId,
+    pub value: &'d str,
+}
+
+/// Elements that are expected to contain dates.
+pub(crate) const DATE_ELEMENTS: &[&str] = &["DBA", "DBB", "DBD", "DDB", "DDC", "DDH", "DDI", "DDJ"];
+
+/// If a value has the eight digits every date element should contain.
+pub(crate) fn is_date_shaped(value: &str) -> bool {
+    value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// If a date element was filled with zeros to show it does not apply, such as
+/// a hazmat expiration date for someone without the endorsement.
+pub(crate) fn is_unset_date(value: &str) -> bool {
+    value.len() == 8 && value.bytes().all(|b| b == b'0')
+}
+
+/// If an expiration date was filled with nines to show the document does not
+/// expire.
+fn is_non_expiring(value: &str) -> bool {
+    value.len() == 8 && value.bytes().all(|b| b == b'9')
+}
+
 fn filter_empty_str<S>(input: S) -> Option<S>
 where
     S: AsRef<str>,
@@ -305,107 +900,127 @@ where
     input.as_ref().is_empty().not().then_some(input)
 }
 
-impl<'a> Data<'a> {
-    pub fn name(&self) -> Option<Name> {
-        match self.header.version_number {
-            ..=1 => {
-                if let Some(family) = self.get_field_owned("DAB") {
-                    let first = self.get_field_owned("DAC")?;
-                    let middle = self.get_field_owned("DAD");
-                    let suffix = self.get_field_owned("DAE");
-                    let prefix = self.get_field_owned("DAF");
-
-                    Some(Name {
-                        family,
-                        first,
-                        middle,
-                        suffix,
-                        prefix,
-                        alias_family: None,
-                        alias_given: None,
-                        alias_suffix: None,
-                        family_truncation: None,
-                        first_truncation: None,
-                        middle_truncation: None,
-                    })
-                } else {
-                    let field = self.get_field("DAA")?;
-                    let split = if field.contains(',') { ',' } else { ' ' };
-                    let mut parts = field.split(split);
-                    let family = parts.next()?.to_string();
-                    let first = parts.next()?.to_string();
-                    let middle = filter_empty_str(parts.join(" "));
-
-                    Some(Name {
-                        family,
-                        first,
-                        middle,
-                        suffix: None,
-                        prefix: None,
-                        alias_family: None,
-                        alias_given: None,
-                        alias_suffix: None,
-                        family_truncation: None,
-                        first_truncation: None,
-                        middle_truncation: None,
-                    })
-                }
-            }
-            2..=3 => {
-                let names = self.get_field("DCT")?;
-
-                let split = if names.contains(',') { ',' } else { ' ' };
-                let mut parts = names.split(split);
-
-                let first = parts.next()?.to_string();
-                let middle = filter_empty_str(parts.join(" "));
-
-                Some(Name {
-                    family: self.get_field_owned("DCS")?,
-                    first,
-                    middle,
-                    suffix: None,
-                    prefix: None,
-                    alias_family: None,
-                    alias_given: None,
-                    alias_suffix: None,
-                    family_truncation: None,
-                    first_truncation: None,
-                    middle_truncation: None,
-                })
+/// Split a run of codes on separators and around codes that are a letter
+/// followed by digits, so `DM2` becomes `D` and `M2`. Other runs of letters,
+/// such as `DJ`, are kept as one code.
+fn split_codes(codes: &str) -> Vec<&str> {
+    let mut split = Vec::new();
+
+    for part in codes.split(|c: char| c.is_whitespace() || c == ',') {
+        let chars = part.char_indices().collect_vec();
+        let mut start = 0;
+
+        for (i, &(index, c)) in chars.iter().enumerate().skip(1) {
+            let after_digits = chars[i - 1].1.is_ascii_digit();
+            let before_digits = chars.get(i + 1).is_some_and(|(_, c)| c.is_ascii_digit());
+
+            if c.is_ascii_alphabetic() && (after_digits || before_digits) {
+                split.push(&part[start..index]);
+                start = index;
             }
-            4.. => Some(Name {
-                family: self.get_field_owned("DCS")?,
-                first: self.get_field_owned("DAC")?,
-                middle: self.get_field_owned("DAD"),
-                suffix: self.get_field_owned("DCU"),
-                prefix: None,
-                alias_family: self.get_field_owned("DBN"),
-                alias_given: self.get_field_owned("DBG"),
-                alias_suffix: self.get_field_owned("DBS"),
-                family_truncation: self.get_field("DDE").and_then(Self::parse_truncation),
-                first_truncation: self.get_field("DDF").and_then(Self::parse_truncation),
-                middle_truncation: self.get_field("DDG").and_then(Self::parse_truncation),
-            }),
         }
+
+        split.push(&part[start..]);
+    }
+
+    split.retain(|code| !code.is_empty());
+    split
+}
+
+/// Split combined names, which are separated by commas or by spaces in some
+/// older barcodes.
+fn split_names(names: &str) -> std::str::Split<'_, char> {
+    let split = if names.contains(',') { ',' } else { ' ' };
+    names.split(split)
+}
+
+impl<'a> Data<'a> {
+    pub fn name(&self) -> Option<Name> {
+        use FieldKind::*;
+
+        let (family, first, middle) = if let Some(family) = self.field_owned(FamilyName)
+            && let Some(first) = self.field_owned(FirstName)
+        {
+            (family, first, self.field_owned(MiddleName))
+        } else if let Some(family) = self.field_owned(FamilyName)
+            && let Some(names) = self.field(GivenNames)
+        {
+            let mut parts = split_names(names);
+            let first = parts.next()?.to_string();
+
+            (family, first, filter_empty_str(parts.join(" ")))
+        } else {
+            let mut parts = split_names(self.field(FullName)?);
+            let family = parts.next()?.to_string();
+            let first = parts.next()?.to_string();
+
+            (family, first, filter_empty_str(parts.join(" ")))
+        };
+
+        Some(Name {
+            family,
+            first,
+            middle,
+            suffix: self.field_owned(NameSuffix),
+            prefix: self.field_owned(NamePrefix),
+            alias_family: self.field_owned(AliasFamilyName),
+            alias_given: self.field_owned(AliasGivenName),
+            alias_suffix: self.field_owned(AliasSuffix),
+            family_truncation: self
+                .field(FamilyNameTruncation)
+                .and_then(Self::parse_truncation),
+            first_truncation: self
+                .field(FirstNameTruncation)
+                .and_then(Self::parse_truncation),
+            middle_truncation: self
+                .field(MiddleNameTruncation)
+                .and_then(Self::parse_truncation),
+        })
     }
 
     pub fn document_expiration_date(&self) -> Option<Date> {
-        self.date_field("DBA")
+        self.date(FieldKind::DocumentExpirationDate)
+    }
+
+    /// When the document expires, recognizing the values jurisdictions use
+    /// for documents that never expire: all nines, all zeros, or a date in
+    /// the year 9999.
+    pub fn expiration(&self) -> Expiration {
+        let Some(value) = self.field(FieldKind::DocumentExpirationDate) else {
+            return Expiration::Unknown;
+        };
+
+        if is_non_expiring(value) || is_unset_date(value) {
+            return Expiration::NonExpiring;
+        }
+
+        match self.document_expiration_date() {
+            Some(date) if date.year() == 9999 => Expiration::NonExpiring,
+            Some(date) => Expiration::On(date),
+            None => Expiration::Unknown,
+        }
     }
 
     pub fn date_of_birth(&self) -> Option<Date> {
-        self.date_field("DBB")
+        self.date(FieldKind::DateOfBirth)
+    }
+
+    /// The date of birth, which may have an unknown month or day written as
+    /// `00`.
+    pub fn partial_date_of_birth(&self) -> Option<PartialDate> {
+        let (id, _) = self.fields(FieldKind::DateOfBirth).next()?;
+
+        self.partial_date_field(id.as_str())
     }
 
     pub fn document_issue_date(&self) -> Option<Date> {
-        self.date_field("DBD")
+        self.date(FieldKind::DocumentIssueDate)
     }
 
     pub fn sex(&self) -> Option<Sex> {
         use Sex::*;
 
-        let sex = match self.get_field("DBC")?.to_ascii_uppercase().as_str() {
+        let sex = match self.field(FieldKind::Sex)?.to_ascii_uppercase().as_str() {
             "1" | "M" => Male,
             "2" | "F" => Female,
             "9" | "X" => NotSpecified,
@@ -418,7 +1033,11 @@ impl<'a> Data<'a> {
     pub fn eye_color(&self) -> Option<EyeColor> {
         use EyeColor::*;
 
-        let color = match self.get_field("DAY")?.to_ascii_uppercase().as_str() {
+        let color = match self
+            .field(FieldKind::EyeColor)?
+            .to_ascii_uppercase()
+            .as_str()
+        {
             "BLK" => Black,
             "BLU" => Blue,
             "BRO" => Brown,
@@ -436,85 +1055,161 @@ impl<'a> Data<'a> {
     }
 
     pub fn height(&self) -> Option<Height> {
-        let height = self.get_field("DAU")?.to_ascii_lowercase();
-
         let parse_hyphenated_ftin = |feet: &str, inches: &str| {
             let feet: u16 = feet.strip_suffix('\'').unwrap_or(feet).parse().ok()?;
             let inches: u16 = inches.strip_suffix('"').unwrap_or(inches).parse().ok()?;
             Some(Height::Inches(feet * 12 + inches))
         };
 
-        if let Some(centimeters) = height.strip_suffix(" cm") {
-            let centimeters = centimeters[..3].parse().ok()?;
-            Some(Height::Centimeters(centimeters))
-        } else if let Some(inches) = height.strip_suffix(" in") {
-            let inches = inches[..3].parse().ok()?;
-            Some(Height::Inches(inches))
-        } else if height.len() == 3 {
-            let feet: u16 = height[..1].parse().ok()?;
-            let inches: u16 = height[1..=2].parse().ok()?;
-            Some(Height::Inches(feet * 12 + inches))
-        } else if let Some((feet, inches)) = height.split_once('-') {
-            parse_hyphenated_ftin(feet, inches)
-        } else if let Some(centimeters) = self.get_field("DAV") {
-            centimeters.parse().ok().map(Height::Centimeters)
-        } else if let Some(Some(height)) = self
-            .subfiles
-            .get(&SubfileType::JurisdictionSpecific('I'))
-            .and_then(|subfile| subfile.get("ZIJ"))
-        {
-            if let Some((feet, inches)) = height.split_once('-') {
+        let parse_height = |height: &str| {
+            let height = height.to_ascii_lowercase();
+
+            if let Some(centimeters) = height.strip_suffix(" cm") {
+                let centimeters = centimeters.get(..3)?.parse().ok()?;
+                Some(Height::Centimeters(centimeters))
+            } else if let Some(inches) = height.strip_suffix(" in") {
+                let inches = inches.get(..3)?.parse().ok()?;
+                Some(Height::Inches(inches))
+            } else if height.len() == 3 {
+                let feet: u16 = height.get(..1)?.parse().ok()?;
+                let inches: u16 = height.get(1..)?.parse().ok()?;
+                Some(Height::Inches(feet * 12 + inches))
+            } else if let Some((feet, inches)) = height.split_once('-') {
                 parse_hyphenated_ftin(feet, inches)
             } else {
                 None
             }
-        } else {
-            None
+        };
+
+        let height = self
+            .fields(FieldKind::Height)
+            .find_map(|(id, value)| match id {
+                ElementId::HeightCentimeters => value.parse().ok().map(Height::Centimeters),
+                _ => parse_height(value),
+            });
+
+        if height.is_some() {
+            return height;
         }
+
+        let (feet, inches) = self
+            .jurisdiction_field(FieldKind::Height)?
+            .split_once('-')?;
+
+        parse_hyphenated_ftin(feet, inches)
     }
 
+    /// The mailing address.
+    ///
+    /// Version 1 of the standard also allows a separate residence address,
+    /// see [`Data::residence_address`]. Later versions only have this one.
     pub fn address(&self) -> Option<Address> {
+        use FieldKind::*;
+
+        self.address_fields([
+            AddressStreet1,
+            AddressStreet2,
+            AddressCity,
+            AddressJurisdictionCode,
+            AddressPostalCode,
+        ])
+    }
+
+    /// The residence address, from the version 1 `DAL` through `DAP`
+    /// elements, if it was given separately from the mailing address.
+    pub fn residence_address(&self) -> Option<Address> {
+        use FieldKind::*;
+
+        self.address_fields([
+            ResidenceStreet1,
+            ResidenceStreet2,
+            ResidenceCity,
+            ResidenceJurisdictionCode,
+            ResidencePostalCode,
+        ])
+    }
+
+    fn address_fields(
+        &self,
+        [street_1, street_2, city, jurisdiction_code, postal_code]: [FieldKind; 5],
+    ) -> Option<Address> {
         Some(Address {
-            address_1: self.get_field_owned("DAG")?,
-            address_2: self.get_field_owned("DAH"),
-            city: self.get_field_owned("DAI")?,
-            jurisdiction_code: self.get_field_owned("DAJ")?,
-            postal_code: self.get_field_owned("DAK")?,
+            address_1: self.field_owned(street_1)?,
+            address_2: self.field_owned(street_2),
+            city: self.field_owned(city)?,
+            jurisdiction_code: self.field_owned(jurisdiction_code)?,
+            postal_code: PostalCode::parse(self.field(postal_code)?, self.address_country()),
+        })
+    }
+
+    /// The first line of the mailing address split into its parts. A unit on the
+    /// second line is included if the first line did not have one.
+    pub fn street_address(&self) -> Option<StreetAddress> {
+        let country = self.address_country();
+        let mut street_address =
+            StreetAddress::parse(self.field(FieldKind::AddressStreet1)?, country)?;
+
+        if street_address.unit.is_none() {
+            street_address.unit = self
+                .field(FieldKind::AddressStreet2)
+                .and_then(|line| Unit::parse(line, country));
+        }
+
+        Some(street_address)
+    }
+
+    /// The country addresses are in, from `DCG` or otherwise the issuer.
+    fn address_country(&self) -> IssuerCountry {
+        self.country_element().flatten().unwrap_or_else(|| {
+            IssuerIdentification::try_from(self.header.issuer_id)
+                .map(|issuer| issuer.country())
+                .unwrap_or_default()
         })
     }
 
     pub fn customer_id_number(&self) -> Option<String> {
-        self.get_field_owned("DAQ")
+        self.field_owned(FieldKind::CustomerIdNumber)
     }
 
     pub fn document_discriminator(&self) -> Option<String> {
-        self.get_field_owned("DCF")
+        self.field_owned(FieldKind::DocumentDiscriminator)
     }
 
     pub fn country(&self) -> Option<IssuerCountry> {
-        let issuer = match self
-            .get_field("DCG")
-            .map(str::to_ascii_uppercase)
-            .as_deref()
+        if let Some(country) = self.country_element() {
+            return country;
+        }
+
+        match self.height()? {
+            Height::Inches(_) => Some(IssuerCountry::UnitedStates),
+            Height::Centimeters(_) => Some(IssuerCountry::Canada),
+        }
+    }
+
+    /// The country in `DCG`, if the element is present.
+    fn country_element(&self) -> Option<Option<IssuerCountry>> {
+        let country = match self
+            .field(FieldKind::Country)?
+            .to_ascii_uppercase()
+            .as_str()
         {
-            Some("USA") => IssuerCountry::UnitedStates,
-            Some("CAN") => IssuerCountry::Canada,
-            Some("MEX") => IssuerCountry::Mexico,
-            Some(_) => return None,
-            _ => match self.height() {
-                Some(Height::Inches(_)) => IssuerCountry::UnitedStates,
-                Some(Height::Centimeters(_)) => IssuerCountry::Canada,
-                _ => return None,
-            },
+            "USA" => Some(IssuerCountry::UnitedStates),
+            "CAN" => Some(IssuerCountry::Canada),
+            "MEX" => Some(IssuerCountry::Mexico),
+            _ => None,
         };
 
-        Some(issuer)
+        Some(country)
     }
 
     pub fn hair_color(&self) -> Option<HairColor> {
         use HairColor::*;
 
-        let color = match self.get_field("DAZ")?.to_ascii_uppercase().as_str() {
+        let color = match self
+            .field(FieldKind::HairColor)?
+            .to_ascii_uppercase()
+            .as_str()
+        {
             "BAL" => Bald,
             "BLK" => Black,
             "BLN" => Blond,
@@ -531,26 +1226,26 @@ impl<'a> Data<'a> {
     }
 
     pub fn place_of_birth(&self) -> Option<String> {
-        self.get_field_owned("DCI")
+        self.field_owned(FieldKind::PlaceOfBirth)
     }
 
     pub fn audit_information(&self) -> Option<String> {
-        self.get_field_owned("DCJ")
+        self.field_owned(FieldKind::AuditInformation)
     }
 
     pub fn inventory_control_information(&self) -> Option<String> {
-        self.get_field_owned("DCK")
+        self.field_owned(FieldKind::InventoryControlNumber)
     }
 
     pub fn weight(&self) -> Option<Weight> {
         use Weight::KilogramRange;
 
-        if let Some(pounds) = self.get_field("DAW") {
-            pounds.parse().ok().map(Weight::Pounds)
-        } else if let Some(kilograms) = self.get_field("DAX") {
-            kilograms.parse().ok().map(Weight::Kilograms)
-        } else if let Some(range) = self.get_field("DCE") {
-            Some(match range {
+        match self.fields(FieldKind::Weight).next()? {
+            (ElementId::WeightPounds, pounds) => pounds.parse().ok().map(Weight::Pounds),
+            (ElementId::WeightKilograms, kilograms) => {
+                kilograms.parse().ok().map(Weight::Kilograms)
+            }
+            (_, range) => Some(match range {
                 "0" => KilogramRange { from: 0, to: 31 },
                 "1" => KilogramRange { from: 32, to: 45 },
                 "2" => KilogramRange { from: 46, to: 59 },
@@ -565,16 +1260,14 @@ impl<'a> Data<'a> {
                     to: u8::MAX,
                 },
                 _ => return None,
-            })
-        } else {
-            None
+            }),
         }
     }
 
     pub fn race(&self) -> Option<Race> {
         use Race::*;
 
-        let race = match self.get_field("DCL")?.to_ascii_uppercase().as_str() {
+        let race = match self.field(FieldKind::Race)?.to_ascii_uppercase().as_str() {
             "AI" => AlaskanAmericanIndian,
             "AP" => AsianPacificIslander,
             "BK" => Black,
@@ -589,59 +1282,283 @@ impl<'a> Data<'a> {
     }
 
     pub fn card_revision_date(&self) -> Option<Date> {
-        self.date_field("DDB")
+        self.date(FieldKind::CardRevisionDate)
     }
 
     pub fn under_age_until(&self) -> UnderAgeUntil {
         UnderAgeUntil {
-            under_18_until: self.date_field("DDH"),
-            under_19_until: self.date_field("DDI"),
-            under_21_until: self.date_field("DDJ"),
+            under_18_until: self.date(FieldKind::Under18Until),
+            under_19_until: self.date(FieldKind::Under19Until),
+            under_21_until: self.date(FieldKind::Under21Until),
         }
     }
 
-    fn date_field(&self, name: &str) -> Option<Date> {
-        let country = IssuerIdentification::try_from(self.header.issuer_id)
-            .map(|issuer| issuer.country())
-            .unwrap_or_default();
+    /// If the document is REAL ID compliant, from `DDA`.
+    ///
+    /// Documents are [`ComplianceType::Legacy`] when their version of the
+    /// standard has no `DDA`.
+    pub fn compliance_type(&self) -> Option<ComplianceType> {
+        if FieldKind::ComplianceType
+            .elements(self.header.version_number)
+            .is_empty()
+        {
+            return Some(ComplianceType::Legacy);
+        }
 
-        let field = self.get_field(name)?;
+        let compliance_type = self.field(FieldKind::ComplianceType)?;
 
-        self.parse_date(field, country)
+        let compliance_type = match compliance_type.to_ascii_uppercase().as_str() {
+            "F" => ComplianceType::FullyCompliant,
+            "N" => ComplianceType::NonCompliant,
+            _ => ComplianceType::Other(compliance_type.to_string()),
+        };
+
+        Some(compliance_type)
     }
 
-    #[tracing::instrument(skip(self))]
-    fn parse_date(&self, input: &str, country: IssuerCountry) -> Option<Date> {
-        if input.len() != 8 {
-            tracing::warn!("date was incorrect length: {input}");
-            return None;
-        }
+    /// If the cardholder is an organ donor, from `DDK` or `DBH` in version 1.
+    pub fn organ_donor(&self) -> Option<bool> {
+        self.field(FieldKind::OrganDonor)
+            .and_then(Self::parse_indicator)
+    }
 
-        let parse_mdy = |input: &str| -> Option<Date> {
-            let month = input[..2].parse().ok()?;
-            let day = input[2..4].parse().ok()?;
-            let year = input[4..].parse().ok()?;
+    /// If the cardholder is a veteran, from `DDL`.
+    pub fn veteran(&self) -> Option<bool> {
+        self.field(FieldKind::Veteran)
+            .and_then(Self::parse_indicator)
+    }
+
+    /// If the document was issued for a limited duration, such as to a
+    /// temporary resident.
+    pub fn limited_duration_document(&self) -> Option<bool> {
+        self.field(FieldKind::LimitedDurationDocument)
+            .and_then(Self::parse_indicator)
+    }
 
-            jiff::civil::Date::new(year, month, day).ok()
+    /// The vehicle classes, restrictions, and endorsements on the document,
+    /// if it has any of them.
+    pub fn privileges(&self) -> Option<Privileges> {
+        use FieldKind::*;
+
+        let privileges = Privileges {
+            vehicle_classes: self.codes(VehicleClass, Self::parse_vehicle_class),
+            restrictions: self.codes(Restrictions, Self::parse_restriction),
+            endorsements: self.codes(Endorsements, Self::parse_endorsement),
+            standard_vehicle_classes: self.codes(StandardVehicleClass, Self::parse_vehicle_class),
+            standard_restrictions: self.codes(StandardRestrictions, Self::parse_restriction),
+            standard_endorsements: self.codes(StandardEndorsements, Self::parse_endorsement),
+            vehicle_class_description: self.field_owned(VehicleClassDescription),
+            restrictions_description: self.field_owned(RestrictionsDescription),
+            endorsements_description: self.field_owned(EndorsementsDescription),
         };
 
-        let parse_ymd = |input: &str| -> Option<Date> {
-            let year = input[..4].parse().ok()?;
-            let month = input[4..6].parse().ok()?;
-            let day = input[6..].parse().ok()?;
+        (privileges != Privileges::default()).then_some(privileges)
+    }
 
-            jiff::civil::Date::new(year, month, day).ok()
-        };
+    pub fn commercial(&self) -> Commercial {
+        let federal_commercial_vehicle_codes =
+            self.field_owned(FieldKind::FederalCommercialVehicleCodes);
+        let hazmat_endorsement_expiration_date =
+            self.date(FieldKind::HazmatEndorsementExpirationDate);
+
+        let commercial_class = self
+            .codes(FieldKind::StandardVehicleClass, Self::parse_vehicle_class)
+            .iter()
+            .any(|class| matches!(class, VehicleClass::A | VehicleClass::B | VehicleClass::C));
+
+        Commercial {
+            is_commercial: commercial_class
+                || federal_commercial_vehicle_codes.is_some()
+                || hazmat_endorsement_expiration_date.is_some(),
+            federal_commercial_vehicle_codes,
+            hazmat_endorsement_expiration_date,
+        }
+    }
+
+    /// Diagnostics for every date element that could not be parsed, was read
+    /// in an unexpected format, or has no plausible year. Expiration dates in
+    /// the year 9999 mean the document does not expire and are not reported.
+    pub(crate) fn date_diagnostics(&self) -> impl Iterator<Item = Diagnostic> + '_ {
+        DATE_ELEMENTS.iter().filter_map(|id| {
+            let value = self.get_field(id)?;
+
+            // Values of the wrong shape are reported while parsing.
+            if !is_date_shaped(value) || is_unset_date(value) {
+                return None;
+            }
 
-        if country == IssuerCountry::UnitedStates && self.header.version_number != 1 {
-            match parse_mdy(input) {
-                None => parse_ymd(input),
-                date => date,
+            if *id == "DBA" && is_non_expiring(value) {
+                return None;
             }
+
+            let Some(resolved) = self.resolve_date(*id) else {
+                if *id == "DBB" && self.partial_date_field(id).is_some() {
+                    return None;
+                }
+
+                return Some(Diagnostic::UnparseableDate {
+                    id: id.to_string(),
+                    value: value.to_string(),
+                });
+            };
+
+            if resolved.resolution == DateResolution::Fallback {
+                Some(Diagnostic::DateFormatFallback {
+                    id: id.to_string(),
+                    value: value.to_string(),
+                    expected: self.date_format(),
+                    used: resolved.format,
+                })
+            } else if resolved.ambiguous && !(*id == "DBA" && resolved.date.year() == 9999) {
+                Some(Diagnostic::AmbiguousDate {
+                    id: id.to_string(),
+                    value: value.to_string(),
+                    used: resolved.format,
+                })
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The format dates are expected to be in, from [`ParseOptions`] if it
+    /// was set there and otherwise from the issuer and version.
+    ///
+    /// [`ParseOptions`]: crate::ParseOptions
+    pub fn date_format(&self) -> DateFormat {
+        self.date_format_override.unwrap_or_else(|| {
+            DateFormat::for_issuer(self.header.issuer_id, self.header.version_number)
+        })
+    }
+
+    /// Parse the date in an element and report how it was read.
+    ///
+    /// When a date is not valid in the expected format, or its year is not
+    /// plausible, the other format is tried unless the format was chosen in
+    /// [`ParseOptions`].
+    ///
+    /// [`ParseOptions`]: crate::ParseOptions
+    pub fn resolve_date(&self, id: impl Into<ElementId>) -> Option<ResolvedDate> {
+        let id = id.into();
+        let input = self
+            .get_field(id.as_str())
+            .filter(|input| !is_unset_date(input))?;
+
+        let readings = self
+            .date_formats()
+            .filter_map(|(format, resolution)| {
+                let date = format.parse(input)?;
+
+                Some(ResolvedDate {
+                    date,
+                    format,
+                    resolution,
+                    ambiguous: !PLAUSIBLE_YEARS.contains(&date.year()),
+                })
+            })
+            .collect_vec();
+
+        let resolved = readings
+            .iter()
+            .find(|resolved| !resolved.ambiguous)
+            .or_else(|| readings.first())
+            .copied();
+
+        resolved.tap_none(|| tracing::warn!("could not parse date {input} in {id}"))
+    }
+
+    /// Formats to try reading dates with, in order.
+    fn date_formats(&self) -> impl Iterator<Item = (DateFormat, DateResolution)> {
+        let format = self.date_format();
+
+        let (resolution, fallback) = if self.date_format_override.is_some() {
+            (DateResolution::Override, None)
         } else {
-            parse_ymd(input)
+            (
+                DateResolution::Issuer,
+                Some((format.other(), DateResolution::Fallback)),
+            )
+        };
+
+        std::iter::once((format, resolution)).chain(fallback)
+    }
+
+    fn date_field(&self, name: &str) -> Option<Date> {
+        self.resolve_date(name).map(|resolved| resolved.date)
+    }
+
+    fn partial_date_field(&self, name: &str) -> Option<PartialDate> {
+        if let Some(date) = self.date_field(name) {
+            return Some(date.into());
+        }
+
+        let input = self
+            .get_field(name)
+            .filter(|input| is_date_shaped(input) && !is_unset_date(input))?;
+
+        self.date_formats()
+            .find_map(|(format, _)| format.parse_partial(input))
+    }
+
+    /// Parse an indicator, which is `1` in current versions and `Y` in version
+    /// 1.
+    fn parse_indicator(input: &str) -> Option<bool> {
+        match input.to_ascii_uppercase().as_str() {
+            "1" | "Y" => Some(true),
+            "0" | "N" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn parse_vehicle_class(code: &str) -> VehicleClass {
+        match code.to_ascii_uppercase().as_str() {
+            "A" => VehicleClass::A,
+            "B" => VehicleClass::B,
+            "C" => VehicleClass::C,
+            "M" => VehicleClass::M,
+            _ => VehicleClass::Other(code.to_string()),
+        }
+    }
+
+    fn parse_restriction(code: &str) -> Restriction {
+        use Restriction::*;
+
+        match code.to_ascii_uppercase().as_str() {
+            "B" => CorrectiveLenses,
+            "C" => MechanicalAid,
+            "D" => ProstheticAid,
+            "E" => AutomaticTransmission,
+            "F" => OutsideMirror,
+            "G" => DaylightOnly,
+            "H" => EmploymentOnly,
+            "I" => LimitedOther,
+            "J" => OtherRestriction,
+            "K" => IntrastateOnly,
+            "L" => NoAirBrakes,
+            "M" => NoClassAPassengerVehicles,
+            "N" => NoClassAOrBPassengerVehicles,
+            "O" => NoTractorTrailer,
+            "P" => NoPassengers,
+            "V" => MedicalVariance,
+            "W" => FarmWaiver,
+            "X" => EmptyTankVehiclesOnly,
+            "Z" => NoFullAirBrakes,
+            _ => Other(code.to_string()),
+        }
+    }
+
+    fn parse_endorsement(code: &str) -> Endorsement {
+        use Endorsement::*;
+
+        match code.to_ascii_uppercase().as_str() {
+            "H" => HazardousMaterials,
+            "N" => TankVehicle,
+            "P" => PassengerVehicle,
+            "S" => SchoolBus,
+            "T" => DoubleTripleTrailers,
+            "X" => TankVehicleHazardousMaterials,
+            _ => Other(code.to_string()),
         }
-        .tap_none(|| tracing::warn!("could not parse date {input} ({country:?})"))
     }
 
     fn parse_truncation(input: &str) -> Option<Truncation> {
@@ -653,18 +1570,549 @@ impl<'a> Data<'a> {
         }
     }
 
+    /// Get the value of an element, trimmed, along with the subfile it was
+    /// found in.
+    ///
+    /// The `DL`, `EN`, and `ID` subfiles are searched in that order, followed
+    /// by every other subfile in the order they appear in the header.
+    /// Elements without a value are skipped.
+    pub fn get(&self, id: impl Into<ElementId>) -> Option<Field<'_>> {
+        let id = id.into();
+
+        self.subfile_types().find_map(|subfile_type| {
+            let value = self.value(subfile_type, id.as_str())?;
+
+            Some(Field {
+                subfile_type,
+                id: id.clone(),
+                value,
+            })
+        })
+    }
+
+    /// Every element with a value, trimmed, in the order they appear in the
+    /// barcode.
+    ///
+    /// Elements that appear more than once in a subfile are only returned
+    /// once, with the value kept in [`Data::subfiles`].
+    pub fn elements(&self) -> impl Iterator<Item = Field<'_>> {
+        self.raw
+            .subfiles
+            .iter()
+            .flat_map(|subfile| {
+                let subfile_type = subfile.designator.subfile_type;
+
+                subfile
+                    .elements
+                    .iter()
+                    .map(move |elem| (subfile_type, elem.id.as_ref()))
+            })
+            .unique()
+            .filter_map(|(subfile_type, id)| {
+                Some(Field {
+                    subfile_type,
+                    id: ElementId::from(id),
+                    value: self.value(subfile_type, id)?,
+                })
+            })
+    }
+
+    /// Subfile types in the order elements are looked up.
+    fn subfile_types(&self) -> impl Iterator<Item = SubfileType> + '_ {
+        let standard = [SubfileType::DL, SubfileType::EN, SubfileType::ID];
+
+        standard.into_iter().chain(
+            self.header
+                .subfile_designators
+                .iter()
+                .map(|designator| designator.subfile_type)
+                .filter(move |subfile_type| !standard.contains(subfile_type))
+                .unique(),
+        )
+    }
+
+    fn value(&self, subfile_type: SubfileType, id: &str) -> Option<&str> {
+        self.subfiles
+            .get(&subfile_type)?
+            .get(id)?
+            .as_deref()
+            .map(str::trim)
+    }
+
     /// Attempt to get a field from known subfile types.
-    fn get_field(&self, name: &str) -> Option<&'a str> {
+    fn get_field(&self, name: &str) -> Option<&str> {
         [SubfileType::DL, SubfileType::EN, SubfileType::ID]
             .into_iter()
-            .find_map(|subfile_type| {
-                self.subfiles
-                    .get(&subfile_type)
-                    .and_then(|subfile| subfile.get(name).cloned().flatten().map(str::trim))
+            .find_map(|subfile_type| self.value(subfile_type, name))
+    }
+
+    /// Every element with a value that stores a field in this version, in
+    /// the order they should be checked.
+    fn fields(&self, kind: FieldKind) -> impl Iterator<Item = (&'static ElementId, &str)> {
+        kind.elements(self.header.version_number)
+            .iter()
+            .filter_map(|id| Some((id, self.get_field(id.as_str())?)))
+    }
+
+    fn field(&self, kind: FieldKind) -> Option<&str> {
+        self.fields(kind).next().map(|(_, value)| value)
+    }
+
+    /// The value of the jurisdiction-specific element that stores a field on
+    /// documents from this issuer.
+    fn jurisdiction_field(&self, kind: FieldKind) -> Option<&str> {
+        let issuer = IssuerIdentification::try_from(self.header.issuer_id).ok()?;
+        let id = kind.jurisdiction_element(issuer)?;
+        let subfile_type = SubfileType::JurisdictionSpecific(id.chars().nth(1)?);
+        self.value(subfile_type, id)
+    }
+
+    fn field_owned(&self, kind: FieldKind) -> Option<String> {
+        self.field(kind).map(str::to_string)
+    }
+
+    /// Every code in a field, split apart and interpreted with `parse`.
+    fn codes<T>(&self, kind: FieldKind, parse: fn(&str) -> T) -> Vec<T> {
+        self.field(kind)
+            .map(|codes| split_codes(codes).into_iter().map(parse).collect())
+            .unwrap_or_default()
+    }
+
+    fn date(&self, kind: FieldKind) -> Option<Date> {
+        let (id, _) = self.fields(kind).next()?;
+
+        self.date_field(id.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{LICENSE_FOLDER, get_test_files, parse_elements};
+    use crate::{BarcodeBuilder, OwnedData, ParseOptions, parse_barcode_bytes, parse_barcode_with};
+
+    #[test]
+    fn test_compliance_type() {
+        use ComplianceType::*;
+
+        let cases = [
+            ("VA-REAL-21.txt", Some(FullyCompliant)),
+            ("VA-21.txt", Some(NonCompliant)),
+        ];
+
+        for (name, expected) in cases {
+            let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/{name}")).unwrap();
+            let data = parse_barcode_bytes(&input).unwrap();
+            assert_eq!(data.compliance_type(), expected, "{name}");
+        }
+
+        let cases = [
+            (3, vec![("DAQ", "1")], Some(Legacy)),
+            (10, vec![("DAQ", "1")], None),
+            (10, vec![("DAQ", "1"), ("DDA", "f")], Some(FullyCompliant)),
+            (
+                10,
+                vec![("DAQ", "1"), ("DDA", "X")],
+                Some(Other("X".to_string())),
+            ),
+        ];
+
+        for (version, elements, expected) in cases {
+            let data = parse_elements(636000, version, &elements);
+            assert_eq!(data.compliance_type(), expected, "{version} {elements:?}");
+        }
+    }
+
+    #[test]
+    fn test_privileges() {
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-21.txt")).unwrap();
+        let data = parse_barcode_bytes(&input).unwrap();
+        assert_eq!(data.privileges(), None);
+
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-REAL-21.txt")).unwrap();
+        let privileges = parse_barcode_bytes(&input).unwrap().privileges().unwrap();
+        assert_eq!(
+            privileges.vehicle_classes,
+            vec![
+                VehicleClass::Other("D".to_string()),
+                VehicleClass::Other("M2".to_string())
+            ]
+        );
+        assert!(privileges.restrictions.is_empty());
+        assert_eq!(privileges.endorsements, vec![Endorsement::SchoolBus]);
+
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-REAL-C-21.txt")).unwrap();
+        let privileges = parse_barcode_bytes(&input).unwrap().privileges().unwrap();
+        assert!(privileges.has_vehicle_class(&VehicleClass::A));
+        assert!(privileges.has_restriction(&Restriction::CorrectiveLenses));
+        assert!(privileges.has_endorsement(&Endorsement::TankVehicleHazardousMaterials));
+
+        let elements = [
+            ("DCA", "C,M"),
+            ("DCB", "B 08"),
+            ("DCD", "NONE"),
+            ("DCN", "P"),
+            ("DCQ", "PASSENGER"),
+        ];
+        let privileges = parse_elements(636000, 10, &elements).privileges().unwrap();
+        assert_eq!(
+            privileges.vehicle_classes,
+            vec![VehicleClass::C, VehicleClass::M]
+        );
+        assert_eq!(
+            privileges.restrictions,
+            vec![
+                Restriction::CorrectiveLenses,
+                Restriction::Other("08".to_string())
+            ]
+        );
+        assert!(privileges.endorsements.is_empty());
+        assert!(privileges.has_endorsement(&Endorsement::PassengerVehicle));
+        assert_eq!(
+            privileges.endorsements_description.as_deref(),
+            Some("PASSENGER")
+        );
+
+        // Multi-letter codes, as New York uses, are kept whole.
+        let cases = [
+            ("DJ", vec!["DJ"]),
+            ("CM", vec!["CM"]),
+            ("DJ MJ", vec!["DJ", "MJ"]),
+            ("B1C2", vec!["B1", "C2"]),
+            ("M2D", vec!["M2", "D"]),
+        ];
+
+        for (value, expected) in cases {
+            let decoded = DecodedData::from(parse_elements(636001, 10, &[("DCA", value)]));
+            let privileges = decoded.privileges.as_ref().unwrap();
+            assert_eq!(
+                privileges.vehicle_classes,
+                expected
+                    .iter()
+                    .map(|code| VehicleClass::Other(code.to_string()))
+                    .collect::<Vec<_>>(),
+                "{value}"
+            );
+
+            // Encoding separates the codes so they are read back the same.
+            let payload = BarcodeBuilder::from_decoded(&decoded, SubfileType::DL)
+                .build()
+                .unwrap();
+            let encoded = DecodedData::from(parse_barcode_bytes(&payload).unwrap());
+            assert_eq!(encoded.privileges, decoded.privileges, "{value}");
+        }
+    }
+
+    #[test]
+    fn test_commercial() {
+        for entry in get_test_files() {
+            let input = std::fs::read(entry.path()).unwrap();
+            let report = parse_barcode_with(&input, &ParseOptions::default()).unwrap();
+            assert!(report.diagnostics.is_empty(), "{:?}", entry.path());
+
+            let commercial = report.data.commercial();
+            let is_cdl = entry.file_name() == "VA-REAL-C-21.txt";
+            assert_eq!(commercial.is_commercial, is_cdl);
+            assert_eq!(
+                commercial.hazmat_endorsement_expiration_date,
+                is_cdl.then_some(jiff::civil::date(2028, 5, 21))
+            );
+        }
+
+        let commercial = parse_elements(636000, 10, &[("DCA", "D"), ("DCH", "S")]).commercial();
+        assert!(commercial.is_commercial);
+        assert_eq!(
+            commercial.federal_commercial_vehicle_codes.as_deref(),
+            Some("S")
+        );
+
+        // California and Texas use class C for regular licenses, with or
+        // without a standard class.
+        let cases = [
+            (636014, vec![("DCA", "C"), ("DCM", "D")], false),
+            (636015, vec![("DCA", "C")], false),
+            (636015, vec![("DCA", "A")], false),
+            (636015, vec![("DCA", "C"), ("DCM", "C")], true),
+        ];
+
+        for (issuer_id, elements, is_commercial) in cases {
+            let data = parse_elements(issuer_id, 10, &elements);
+            assert_eq!(
+                data.commercial().is_commercial,
+                is_commercial,
+                "{elements:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_dates() {
+        use crate::age::AgeSource;
+        use jiff::civil::date;
+
+        let cases = [
+            (636000, "00001980", Some("1980"), Some(date(1980, 12, 31))),
+            (636000, "05001980", Some("1980-05"), Some(date(1980, 5, 31))),
+            (636000, "00151980", Some("1980"), Some(date(1980, 12, 31))),
+            (636000, "02002024", Some("2024-02"), Some(date(2024, 2, 29))),
+            (636012, "19800500", Some("1980-05"), Some(date(1980, 5, 31))),
+            (
+                636000,
+                "05151980",
+                Some("1980-05-15"),
+                Some(date(1980, 5, 15)),
+            ),
+            (636000, "13001980", None, None),
+        ];
+
+        for (issuer_id, value, expected, latest) in cases {
+            let payload = BarcodeBuilder::new(issuer_id, 10)
+                .element(SubfileType::DL, "DBB", value)
+                .build()
+                .unwrap();
+            let report = parse_barcode_with(&payload, &ParseOptions::default()).unwrap();
+
+            let partial = report.data.partial_date_of_birth();
+            assert_eq!(
+                partial.map(|date| date.to_string()).as_deref(),
+                expected,
+                "{value}"
+            );
+            assert_eq!(partial.and_then(|date| date.latest()), latest, "{value}");
+            assert_eq!(report.diagnostics.is_empty(), expected.is_some(), "{value}");
+
+            // Encoding a partial date writes its unknown parts back as zeros.
+            let decoded = DecodedData::from(report.data);
+            let payload = BarcodeBuilder::from_decoded(&decoded, SubfileType::DL)
+                .build()
+                .unwrap();
+            let data = parse_barcode_bytes(&payload).unwrap();
+            assert_eq!(data.partial_date_of_birth(), partial, "{value}");
+        }
+
+        let decoded = DecodedData::from(parse_elements(636000, 10, &[("DBB", "00002004")]));
+        assert_eq!(decoded.date_of_birth, None);
+
+        let check = decoded.is_at_least(21, date(2025, 6, 1)).unwrap();
+        assert!(!check.value);
+        assert_eq!(check.source, AgeSource::PartialDateOfBirth);
+        assert!(decoded.is_at_least(21, date(2025, 12, 31)).unwrap().value);
+        assert_eq!(decoded.age_on(date(2025, 12, 30)).unwrap().value, 20);
+    }
+
+    #[test]
+    fn test_date_formats() {
+        use jiff::civil::date;
+
+        assert_eq!(DateFormat::for_issuer(636000, 1), DateFormat::YearMonthDay);
+        assert_eq!(DateFormat::for_issuer(636000, 10), DateFormat::MonthDayYear);
+        assert_eq!(DateFormat::for_issuer(636012, 10), DateFormat::YearMonthDay);
+        assert_eq!(DateFormat::for_issuer(1, 10), DateFormat::MonthDayYear);
+        assert_eq!(DateFormat::for_issuer(636012, 11), DateFormat::YearMonthDay);
+        assert_eq!(DateFormat::for_issuer(636000, 11), DateFormat::MonthDayYear);
+
+        // Every issuer in the table has exactly one format for each version.
+        for (issuer, ..) in ISSUER_DATE_FORMATS {
+            for version in 1..=10 {
+                let rows = ISSUER_DATE_FORMATS
+                    .iter()
+                    .filter(|(id, versions, _)| id == issuer && versions.contains(&version))
+                    .count();
+                assert_eq!(rows, 1, "{issuer:?} version {version}");
+            }
+        }
+
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-21.txt")).unwrap();
+        let data = parse_barcode_bytes(&input).unwrap();
+        assert_eq!(
+            data.resolve_date(ElementId::DateOfBirth),
+            Some(ResolvedDate {
+                date: date(1950, 3, 23),
+                format: DateFormat::MonthDayYear,
+                resolution: DateResolution::Issuer,
+                ambiguous: false,
             })
+        );
+
+        let cases = [
+            // A Canadian card with a US style date.
+            (
+                636012,
+                "05142023",
+                None,
+                Some((date(2023, 5, 14), DateResolution::Fallback, false)),
+                Some(Diagnostic::DateFormatFallback {
+                    id: "DBB".to_string(),
+                    value: "05142023".to_string(),
+                    expected: DateFormat::YearMonthDay,
+                    used: DateFormat::MonthDayYear,
+                }),
+            ),
+            (
+                636000,
+                "12311899",
+                None,
+                Some((date(1899, 12, 31), DateResolution::Issuer, true)),
+                Some(Diagnostic::AmbiguousDate {
+                    id: "DBB".to_string(),
+                    value: "12311899".to_string(),
+                    used: DateFormat::MonthDayYear,
+                }),
+            ),
+            (
+                636012,
+                "21100102",
+                None,
+                Some((date(2110, 1, 2), DateResolution::Issuer, true)),
+                Some(Diagnostic::AmbiguousDate {
+                    id: "DBB".to_string(),
+                    value: "21100102".to_string(),
+                    used: DateFormat::YearMonthDay,
+                }),
+            ),
+            (
+                636000,
+                "01011900",
+                None,
+                Some((date(1900, 1, 1), DateResolution::Issuer, false)),
+                None,
+            ),
+            (
+                636000,
+                "20230514",
+                Some(DateFormat::YearMonthDay),
+                Some((date(2023, 5, 14), DateResolution::Override, false)),
+                None,
+            ),
+            // Overrides are never fallen back from.
+            (
+                636000,
+                "05142023",
+                Some(DateFormat::YearMonthDay),
+                None,
+                Some(Diagnostic::UnparseableDate {
+                    id: "DBB".to_string(),
+                    value: "05142023".to_string(),
+                }),
+            ),
+        ];
+
+        for (issuer_id, value, date_format, expected, diagnostic) in cases {
+            let payload = BarcodeBuilder::new(issuer_id, 10)
+                .element(SubfileType::DL, "DBB", value)
+                .build()
+                .unwrap();
+            let report = parse_barcode_with(
+                &payload,
+                &ParseOptions {
+                    date_format,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+            let resolved = report.data.resolve_date("DBB");
+            assert_eq!(
+                resolved.map(|resolved| (resolved.date, resolved.resolution, resolved.ambiguous)),
+                expected,
+                "{value}"
+            );
+            assert_eq!(
+                report.data.date_of_birth(),
+                expected.map(|(date, ..)| date),
+                "{value}"
+            );
+            assert_eq!(report.diagnostics, Vec::from_iter(diagnostic), "{value}");
+
+            // The format is kept through serialization and decoding.
+            let json = serde_json::to_string(&report.data.into_owned()).unwrap();
+            let data: OwnedData = serde_json::from_str(&json).unwrap();
+            assert_eq!(
+                data.date_format(),
+                date_format.unwrap_or(DateFormat::for_issuer(issuer_id, 10))
+            );
+            assert_eq!(
+                DecodedData::from(data).date_of_birth,
+                expected.map(|(date, ..)| date),
+                "{value}"
+            );
+        }
     }
 
-    fn get_field_owned(&self, name: &str) -> Option<String> {
-        self.get_field(name).map(str::to_string)
+    #[test]
+    fn test_indicators() {
+        for entry in get_test_files() {
+            let input = std::fs::read(entry.path()).unwrap();
+            let data = parse_barcode_bytes(&input).unwrap();
+            assert_eq!(data.limited_duration_document(), Some(false));
+        }
+
+        let cases = [
+            (
+                1,
+                vec![("DBH", "Y"), ("DDK", "1")],
+                (Some(true), None, None),
+            ),
+            (
+                7,
+                vec![("DDK", "1"), ("DDL", "1"), ("DDD", "1")],
+                (None, None, Some(true)),
+            ),
+            (
+                10,
+                vec![("DDK", "1"), ("DDL", "0"), ("DDD", "1")],
+                (Some(true), Some(false), Some(true)),
+            ),
+        ];
+
+        for (version, elements, expected) in cases {
+            let data = parse_elements(636000, version, &elements);
+
+            assert_eq!(
+                (
+                    data.organ_donor(),
+                    data.veteran(),
+                    data.limited_duration_document()
+                ),
+                expected,
+                "version {version}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_residence_address() {
+        for entry in get_test_files() {
+            let input = std::fs::read(entry.path()).unwrap();
+            let data = parse_barcode_bytes(&input).unwrap();
+            assert!(data.address().is_some());
+            assert!(data.residence_address().is_none());
+        }
+
+        let elements = [
+            ("DAG", "PO BOX 1"),
+            ("DAI", "RICHMOND"),
+            ("DAJ", "VA"),
+            ("DAK", "23218"),
+            ("DAL", "110 EAST MIDLAND TRL"),
+            ("DAN", "RICHMOND"),
+            ("DAO", "VA"),
+            ("DAP", "232200000"),
+        ];
+
+        for version in [1, 10] {
+            let data = parse_elements(636000, version, &elements);
+
+            assert_eq!(data.address().unwrap().address_1, "PO BOX 1");
+
+            let residence_address = data.residence_address();
+            if version == 1 {
+                let residence_address = residence_address.unwrap();
+                assert_eq!(residence_address.address_1, "110 EAST MIDLAND TRL");
+                assert_eq!(residence_address.postal_code.to_string(), "23220");
+            } else {
+                assert!(residence_address.is_none());
+            }
+        }
     }
 }
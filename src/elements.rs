@@ -0,0 +1,1027 @@
+//! A catalog of the data elements defined by each version of the AAMVA DL/ID
+//! Card Design Standard.
+
+use std::{borrow::Cow, ops::RangeInclusive};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SubfileType, data::IssuerIdentification};
+
+use CharacterClass::{Alpha as A, AlphaNumeric as AN, AlphaNumericSpecial as ANS, Numeric as N};
+use Length::{Fixed as F, Variable as V};
+
+macro_rules! element_ids {
+    ($($variant:ident => $id:literal,)*) => {
+        /// The ID of a data element, with a variant for every element defined
+        /// by the standard.
+        ///
+        /// Variants are named after the most recent meaning of each ID.
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum ElementId {
+            $(
+                #[doc = concat!("`", $id, "`")]
+                $variant,
+            )*
+            /// Any other element, such as those in jurisdiction-specific
+            /// subfiles.
+            Other(String),
+        }
+
+        impl ElementId {
+            /// The three letter ID of the element.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $id,)*
+                    Self::Other(id) => id,
+                }
+            }
+        }
+
+        impl From<&str> for ElementId {
+            fn from(id: &str) -> Self {
+                match id {
+                    $($id => Self::$variant,)*
+                    id => Self::Other(id.to_string()),
+                }
+            }
+        }
+    };
+}
+
+element_ids! {
+    FullName => "DAA",
+    LastName => "DAB",
+    FirstName => "DAC",
+    MiddleName => "DAD",
+    LegacyNameSuffix => "DAE",
+    NamePrefix => "DAF",
+    AddressStreet1 => "DAG",
+    AddressStreet2 => "DAH",
+    AddressCity => "DAI",
+    AddressJurisdictionCode => "DAJ",
+    AddressPostalCode => "DAK",
+    ResidenceStreet1 => "DAL",
+    ResidenceStreet2 => "DAM",
+    ResidenceCity => "DAN",
+    ResidenceJurisdictionCode => "DAO",
+    ResidencePostalCode => "DAP",
+    CustomerIdNumber => "DAQ",
+    LicenseClassification => "DAR",
+    LicenseRestrictions => "DAS",
+    LicenseEndorsements => "DAT",
+    Height => "DAU",
+    HeightCentimeters => "DAV",
+    WeightPounds => "DAW",
+    WeightKilograms => "DAX",
+    EyeColor => "DAY",
+    HairColor => "DAZ",
+    DocumentExpirationDate => "DBA",
+    DateOfBirth => "DBB",
+    Sex => "DBC",
+    DocumentIssueDate => "DBD",
+    IssueTimestamp => "DBE",
+    NumberOfDuplicates => "DBF",
+    AliasGivenName => "DBG",
+    LegacyOrganDonor => "DBH",
+    NonResident => "DBI",
+    UniqueCustomerIdentifier => "DBJ",
+    SocialSecurityNumber => "DBK",
+    AliasDateOfBirth => "DBL",
+    AliasSocialSecurityNumber => "DBM",
+    AliasFamilyName => "DBN",
+    AliasLastName => "DBO",
+    AliasFirstName => "DBP",
+    LegacyAliasNameSuffix => "DBQ",
+    AliasNameSuffix => "DBS",
+    VehicleClass => "DCA",
+    RestrictionCodes => "DCB",
+    EndorsementCodes => "DCD",
+    WeightRange => "DCE",
+    DocumentDiscriminator => "DCF",
+    Country => "DCG",
+    FederalCommercialVehicleCodes => "DCH",
+    PlaceOfBirth => "DCI",
+    AuditInformation => "DCJ",
+    InventoryControlNumber => "DCK",
+    Race => "DCL",
+    StandardVehicleClassification => "DCM",
+    StandardEndorsementCode => "DCN",
+    StandardRestrictionCode => "DCO",
+    VehicleClassDescription => "DCP",
+    EndorsementCodeDescription => "DCQ",
+    RestrictionCodeDescription => "DCR",
+    FamilyName => "DCS",
+    GivenNames => "DCT",
+    NameSuffix => "DCU",
+    ComplianceType => "DDA",
+    CardRevisionDate => "DDB",
+    HazmatEndorsementExpirationDate => "DDC",
+    LimitedDurationDocument => "DDD",
+    FamilyNameTruncation => "DDE",
+    FirstNameTruncation => "DDF",
+    MiddleNameTruncation => "DDG",
+    Under18Until => "DDH",
+    Under19Until => "DDI",
+    Under21Until => "DDJ",
+    OrganDonor => "DDK",
+    Veteran => "DDL",
+}
+
+impl ElementId {
+    /// The definition of the element, if it is defined by the standard.
+    pub fn definition(&self) -> Option<&'static ElementDefinition> {
+        element(self.as_str())
+    }
+}
+
+impl std::fmt::Display for ElementId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for ElementId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ElementId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let id = Cow::<str>::deserialize(deserializer)?;
+
+        Ok(Self::from(id.as_ref()))
+    }
+}
+
+/// A data element and how each version of the standard defines it.
+#[derive(Debug, Serialize)]
+pub struct ElementDefinition {
+    pub id: &'static str,
+    /// Definitions in version order. Versions not covered by any definition
+    /// do not define the element.
+    pub versions: &'static [ElementVersion],
+}
+
+/// How a range of versions of the standard define an element.
+#[derive(Debug, Serialize)]
+pub struct ElementVersion {
+    pub versions: RangeInclusive<u8>,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// If the element must be present on driver licenses.
+    pub mandatory_dl: bool,
+    /// If the element must be present on identification cards.
+    pub mandatory_id: bool,
+    pub length: Length,
+    pub characters: CharacterClass,
+}
+
+/// The allowed length of an element value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Length {
+    /// Values are always exactly this long.
+    Fixed(u16),
+    /// Values can be up to this long.
+    Variable(u16),
+}
+
+impl Length {
+    pub fn max(&self) -> u16 {
+        match self {
+            Self::Fixed(length) | Self::Variable(length) => *length,
+        }
+    }
+}
+
+/// The characters allowed in an element value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CharacterClass {
+    /// Letters and spaces, `A` in the standard.
+    Alpha,
+    /// Digits, `N` in the standard.
+    Numeric,
+    /// Letters, digits, and spaces, `AN` in the standard.
+    AlphaNumeric,
+    /// Any printable character, `ANS` in the standard.
+    AlphaNumericSpecial,
+}
+
+impl CharacterClass {
+    /// If every character in a value is allowed.
+    pub fn matches(&self, value: &str) -> bool {
+        value.chars().all(|c| match self {
+            Self::Alpha => c.is_alphabetic() || c == ' ',
+            Self::Numeric => c.is_ascii_digit(),
+            Self::AlphaNumeric => c.is_alphanumeric() || c == ' ',
+            Self::AlphaNumericSpecial => !c.is_control(),
+        })
+    }
+}
+
+impl ElementDefinition {
+    /// The definition of this element in a version of the standard.
+    pub fn version(&self, version: u8) -> Option<&ElementVersion> {
+        self.versions
+            .iter()
+            .find(|definition| definition.versions.contains(&version))
+    }
+
+    /// The most recent definition of this element.
+    pub fn latest(&self) -> &ElementVersion {
+        self.versions
+            .last()
+            .expect("every element should have a definition")
+    }
+}
+
+impl ElementVersion {
+    /// If the element must be present in a subfile of the given type.
+    ///
+    /// Only `DL` and `ID` subfiles have mandatory elements.
+    pub fn is_mandatory(&self, subfile_type: SubfileType) -> bool {
+        match subfile_type {
+            SubfileType::DL => self.mandatory_dl,
+            SubfileType::ID => self.mandatory_id,
+            _ => false,
+        }
+    }
+}
+
+/// Look up an element by its three letter ID.
+pub fn element(id: &str) -> Option<&'static ElementDefinition> {
+    ELEMENTS.get(id)
+}
+
+const fn required(
+    versions: RangeInclusive<u8>,
+    name: &'static str,
+    description: &'static str,
+    length: Length,
+    characters: CharacterClass,
+) -> ElementVersion {
+    ElementVersion {
+        versions,
+        name,
+        description,
+        mandatory_dl: true,
+        mandatory_id: true,
+        length,
+        characters,
+    }
+}
+
+/// Mandatory on driver licenses but not on identification cards.
+const fn required_dl(
+    versions: RangeInclusive<u8>,
+    name: &'static str,
+    description: &'static str,
+    length: Length,
+    characters: CharacterClass,
+) -> ElementVersion {
+    ElementVersion {
+        mandatory_id: false,
+        ..required(versions, name, description, length, characters)
+    }
+}
+
+const fn optional(
+    versions: RangeInclusive<u8>,
+    name: &'static str,
+    description: &'static str,
+    length: Length,
+    characters: CharacterClass,
+) -> ElementVersion {
+    ElementVersion {
+        mandatory_dl: false,
+        mandatory_id: false,
+        ..required(versions, name, description, length, characters)
+    }
+}
+
+/// Every data element in the `DL` and `ID` subfiles of versions 1 through 10,
+/// keyed by element ID.
+pub static ELEMENTS: phf::Map<&'static str, ElementDefinition> = phf::phf_map! {
+    "DAA" => ElementDefinition {
+        id: "DAA",
+        versions: &[optional(1..=1, "Full Name", "Full name, with the family name first and each name separated by a comma.", V(125), ANS)],
+    },
+    "DAB" => ElementDefinition {
+        id: "DAB",
+        versions: &[optional(1..=1, "Family Name", "Family name, used instead of the full name.", V(40), ANS)],
+    },
+    "DAC" => ElementDefinition {
+        id: "DAC",
+        versions: &[
+            optional(1..=1, "Given Name", "First name, used instead of the full name.", V(40), ANS),
+            required(4..=10, "Customer First Name", "First name of the cardholder.", V(40), ANS),
+        ],
+    },
+    "DAD" => ElementDefinition {
+        id: "DAD",
+        versions: &[
+            optional(1..=1, "Middle Name", "Middle name or initial, used instead of the full name.", V(40), ANS),
+            required(4..=10, "Customer Middle Name(s)", "Middle names of the cardholder, separated by commas.", V(40), ANS),
+        ],
+    },
+    "DAE" => ElementDefinition {
+        id: "DAE",
+        versions: &[optional(1..=1, "Name Suffix", "Suffix such as JR or III, used instead of the full name.", V(5), ANS)],
+    },
+    "DAF" => ElementDefinition {
+        id: "DAF",
+        versions: &[optional(1..=1, "Name Prefix", "Prefix such as MR or DR, used instead of the full name.", V(5), ANS)],
+    },
+    "DAG" => ElementDefinition {
+        id: "DAG",
+        versions: &[required(1..=10, "Address - Street 1", "First line of the street portion of the mailing address.", V(35), ANS)],
+    },
+    "DAH" => ElementDefinition {
+        id: "DAH",
+        versions: &[optional(1..=10, "Address - Street 2", "Second line of the street portion of the mailing address.", V(35), ANS)],
+    },
+    "DAI" => ElementDefinition {
+        id: "DAI",
+        versions: &[required(1..=10, "Address - City", "City portion of the mailing address.", V(20), ANS)],
+    },
+    "DAJ" => ElementDefinition {
+        id: "DAJ",
+        versions: &[required(1..=10, "Address - Jurisdiction Code", "State or province portion of the mailing address.", F(2), A)],
+    },
+    "DAK" => ElementDefinition {
+        id: "DAK",
+        versions: &[required(1..=10, "Address - Postal Code", "Postal code portion of the mailing address.", F(11), ANS)],
+    },
+    "DAL" => ElementDefinition {
+        id: "DAL",
+        versions: &[optional(1..=1, "Residence Street Address 1", "First line of the street portion of the residence address.", V(35), ANS)],
+    },
+    "DAM" => ElementDefinition {
+        id: "DAM",
+        versions: &[optional(1..=1, "Residence Street Address 2", "Second line of the street portion of the residence address.", V(35), ANS)],
+    },
+    "DAN" => ElementDefinition {
+        id: "DAN",
+        versions: &[optional(1..=1, "Residence City", "City portion of the residence address.", V(20), ANS)],
+    },
+    "DAO" => ElementDefinition {
+        id: "DAO",
+        versions: &[optional(1..=1, "Residence Jurisdiction Code", "State or province portion of the residence address.", F(2), A)],
+    },
+    "DAP" => ElementDefinition {
+        id: "DAP",
+        versions: &[optional(1..=1, "Residence Postal Code", "Postal code portion of the residence address.", F(11), ANS)],
+    },
+    "DAQ" => ElementDefinition {
+        id: "DAQ",
+        versions: &[required(1..=10, "Customer ID Number", "Number assigned to the cardholder by the issuing jurisdiction.", V(25), ANS)],
+    },
+    "DAR" => ElementDefinition {
+        id: "DAR",
+        versions: &[required_dl(1..=1, "License Classification Code", "Class of vehicles the license permits driving.", F(4), AN)],
+    },
+    "DAS" => ElementDefinition {
+        id: "DAS",
+        versions: &[required_dl(1..=1, "License Restriction Code", "Restrictions on driving privileges.", F(10), AN)],
+    },
+    "DAT" => ElementDefinition {
+        id: "DAT",
+        versions: &[required_dl(1..=1, "License Endorsements Code", "Additional driving privileges.", F(5), AN)],
+    },
+    "DAU" => ElementDefinition {
+        id: "DAU",
+        versions: &[
+            optional(1..=1, "Height (FT/IN)", "Height as one digit of feet and two digits of inches.", F(3), N),
+            required(2..=10, "Physical Description - Height", "Height followed by \"in\" for inches or \"cm\" for centimeters.", F(6), ANS),
+        ],
+    },
+    "DAV" => ElementDefinition {
+        id: "DAV",
+        versions: &[optional(1..=1, "Height (CM)", "Height in centimeters.", F(3), N)],
+    },
+    "DAW" => ElementDefinition {
+        id: "DAW",
+        versions: &[
+            optional(1..=1, "Weight (LBS)", "Weight in pounds.", F(3), N),
+            optional(4..=10, "Weight (pounds)", "Weight in pounds.", F(3), N),
+        ],
+    },
+    "DAX" => ElementDefinition {
+        id: "DAX",
+        versions: &[
+            optional(1..=1, "Weight (KG)", "Weight in kilograms.", F(3), N),
+            optional(4..=10, "Weight (kilograms)", "Weight in kilograms.", F(3), N),
+        ],
+    },
+    "DAY" => ElementDefinition {
+        id: "DAY",
+        versions: &[
+            optional(1..=1, "Eye Color", "Eye color as an ANSI D-20 code.", F(3), A),
+            required(2..=10, "Physical Description - Eye Color", "Eye color as an ANSI D-20 code.", F(3), A),
+        ],
+    },
+    "DAZ" => ElementDefinition {
+        id: "DAZ",
+        versions: &[
+            optional(1..=1, "Hair Color", "Hair color as an ANSI D-20 code.", F(3), A),
+            optional(2..=10, "Hair Color", "Hair color as an ANSI D-20 code.", V(12), A),
+        ],
+    },
+    "DBA" => ElementDefinition {
+        id: "DBA",
+        versions: &[required(1..=10, "Document Expiration Date", "Date the document expires.", F(8), N)],
+    },
+    "DBB" => ElementDefinition {
+        id: "DBB",
+        versions: &[required(1..=10, "Date of Birth", "Date the cardholder was born.", F(8), N)],
+    },
+    "DBC" => ElementDefinition {
+        id: "DBC",
+        versions: &[
+            required(1..=1, "Sex", "Sex as M or F.", F(1), A),
+            required(2..=10, "Physical Description - Sex", "Sex as 1 for male, 2 for female, or 9 for not specified.", F(1), N),
+        ],
+    },
+    "DBD" => ElementDefinition {
+        id: "DBD",
+        versions: &[required(1..=10, "Document Issue Date", "Date the document was issued.", F(8), N)],
+    },
+    "DBE" => ElementDefinition {
+        id: "DBE",
+        versions: &[optional(1..=1, "Issue Timestamp", "Time the document was issued, for jurisdiction use.", V(20), ANS)],
+    },
+    "DBF" => ElementDefinition {
+        id: "DBF",
+        versions: &[optional(1..=1, "Number of Duplicates", "Number of duplicate documents issued.", F(2), N)],
+    },
+    "DBG" => ElementDefinition {
+        id: "DBG",
+        versions: &[
+            optional(1..=1, "Medical Indicator/Codes", "Medical conditions relevant to driving.", V(20), ANS),
+            optional(2..=10, "Alias / AKA Given Name", "Other given name used by the cardholder.", V(15), ANS),
+        ],
+    },
+    "DBH" => ElementDefinition {
+        id: "DBH",
+        versions: &[optional(1..=1, "Organ Donor", "Y if the cardholder is an organ donor.", F(1), A)],
+    },
+    "DBI" => ElementDefinition {
+        id: "DBI",
+        versions: &[optional(1..=1, "Non-Resident Indicator", "Y if the cardholder does not reside in the jurisdiction.", F(1), A)],
+    },
+    "DBJ" => ElementDefinition {
+        id: "DBJ",
+        versions: &[optional(1..=1, "Unique Customer Identifier", "Identifier for the cardholder used by the jurisdiction.", V(25), ANS)],
+    },
+    "DBK" => ElementDefinition {
+        id: "DBK",
+        versions: &[optional(1..=1, "Social Security Number", "Social security number of the cardholder.", F(9), N)],
+    },
+    "DBL" => ElementDefinition {
+        id: "DBL",
+        versions: &[optional(1..=1, "Date of Birth (Alias)", "Other date of birth used by the cardholder.", F(8), N)],
+    },
+    "DBM" => ElementDefinition {
+        id: "DBM",
+        versions: &[optional(1..=1, "Social Security Number (Alias)", "Other social security number used by the cardholder.", F(9), N)],
+    },
+    "DBN" => ElementDefinition {
+        id: "DBN",
+        versions: &[
+            optional(1..=1, "Full Name (Alias)", "Other full name used by the cardholder.", V(125), ANS),
+            optional(2..=10, "Alias / AKA Family Name", "Other family name used by the cardholder.", V(10), ANS),
+        ],
+    },
+    "DBO" => ElementDefinition {
+        id: "DBO",
+        versions: &[optional(1..=1, "Family Name (Alias)", "Other family name used by the cardholder.", V(40), ANS)],
+    },
+    "DBP" => ElementDefinition {
+        id: "DBP",
+        versions: &[optional(1..=1, "Given Name (Alias)", "Other given name used by the cardholder.", V(40), ANS)],
+    },
+    "DBQ" => ElementDefinition {
+        id: "DBQ",
+        versions: &[optional(1..=1, "Name Suffix (Alias)", "Other name suffix used by the cardholder.", V(5), ANS)],
+    },
+    "DBS" => ElementDefinition {
+        id: "DBS",
+        versions: &[optional(2..=10, "Alias / AKA Suffix Name", "Other name suffix used by the cardholder.", V(5), ANS)],
+    },
+    "DCA" => ElementDefinition {
+        id: "DCA",
+        versions: &[required_dl(2..=10, "Jurisdiction-specific Vehicle Class", "Class of vehicles the license permits driving.", V(6), ANS)],
+    },
+    "DCB" => ElementDefinition {
+        id: "DCB",
+        versions: &[required_dl(2..=10, "Jurisdiction-specific Restriction Codes", "Restrictions on driving privileges.", V(12), ANS)],
+    },
+    "DCD" => ElementDefinition {
+        id: "DCD",
+        versions: &[required_dl(2..=10, "Jurisdiction-specific Endorsement Codes", "Additional driving privileges.", V(5), ANS)],
+    },
+    "DCE" => ElementDefinition {
+        id: "DCE",
+        versions: &[optional(2..=10, "Physical Description - Weight Range", "Weight as a range of kilograms from 0 to 9.", F(1), N)],
+    },
+    "DCF" => ElementDefinition {
+        id: "DCF",
+        versions: &[required(2..=10, "Document Discriminator", "Number identifying this particular document.", V(25), ANS)],
+    },
+    "DCG" => ElementDefinition {
+        id: "DCG",
+        versions: &[required(2..=10, "Country Identification", "Country of issuance as USA or CAN.", F(3), A)],
+    },
+    "DCH" => ElementDefinition {
+        id: "DCH",
+        versions: &[optional(2..=10, "Federal Commercial Vehicle Codes", "Federal codes for commercial vehicle classes.", F(4), ANS)],
+    },
+    "DCI" => ElementDefinition {
+        id: "DCI",
+        versions: &[optional(2..=10, "Place of Birth", "Country and municipality or state or province of birth.", V(33), A)],
+    },
+    "DCJ" => ElementDefinition {
+        id: "DCJ",
+        versions: &[optional(2..=10, "Audit Information", "Information used to audit how the document was produced.", V(25), ANS)],
+    },
+    "DCK" => ElementDefinition {
+        id: "DCK",
+        versions: &[optional(2..=10, "Inventory Control Number", "Number identifying the physical card stock.", V(25), ANS)],
+    },
+    "DCL" => ElementDefinition {
+        id: "DCL",
+        versions: &[optional(2..=10, "Race / Ethnicity", "Race or ethnicity as an ANSI D-20 code.", V(3), A)],
+    },
+    "DCM" => ElementDefinition {
+        id: "DCM",
+        versions: &[optional(2..=10, "Standard Vehicle Classification", "Standard vehicle class, for jurisdictions using non-standard codes.", F(4), AN)],
+    },
+    "DCN" => ElementDefinition {
+        id: "DCN",
+        versions: &[optional(2..=10, "Standard Endorsement Code", "Standard endorsements, for jurisdictions using non-standard codes.", F(5), AN)],
+    },
+    "DCO" => ElementDefinition {
+        id: "DCO",
+        versions: &[optional(2..=10, "Standard Restriction Code", "Standard restrictions, for jurisdictions using non-standard codes.", F(12), AN)],
+    },
+    "DCP" => ElementDefinition {
+        id: "DCP",
+        versions: &[optional(2..=10, "Jurisdiction-specific Vehicle Classification Description", "Text describing the jurisdiction-specific vehicle class.", V(50), ANS)],
+    },
+    "DCQ" => ElementDefinition {
+        id: "DCQ",
+        versions: &[optional(2..=10, "Jurisdiction-specific Endorsement Code Description", "Text describing the jurisdiction-specific endorsements.", V(50), ANS)],
+    },
+    "DCR" => ElementDefinition {
+        id: "DCR",
+        versions: &[optional(2..=10, "Jurisdiction-specific Restriction Code Description", "Text describing the jurisdiction-specific restrictions.", V(50), ANS)],
+    },
+    "DCS" => ElementDefinition {
+        id: "DCS",
+        versions: &[required(2..=10, "Customer Family Name", "Family name of the cardholder.", V(40), ANS)],
+    },
+    "DCT" => ElementDefinition {
+        id: "DCT",
+        versions: &[required(2..=3, "Customer Given Names", "First and middle names of the cardholder, separated by a comma.", V(80), ANS)],
+    },
+    "DCU" => ElementDefinition {
+        id: "DCU",
+        versions: &[optional(2..=10, "Name Suffix", "Suffix such as JR or III.", V(5), ANS)],
+    },
+    "DDA" => ElementDefinition {
+        id: "DDA",
+        versions: &[optional(4..=10, "Compliance Type", "F for REAL ID compliant documents and N for non-compliant ones.", F(1), A)],
+    },
+    "DDB" => ElementDefinition {
+        id: "DDB",
+        versions: &[optional(4..=10, "Card Revision Date", "Date the design of the card was last revised.", F(8), N)],
+    },
+    "DDC" => ElementDefinition {
+        id: "DDC",
+        versions: &[optional(4..=10, "HAZMAT Endorsement Expiration Date", "Date the hazardous materials endorsement expires.", F(8), N)],
+    },
+    "DDD" => ElementDefinition {
+        id: "DDD",
+        versions: &[optional(4..=10, "Limited Duration Document Indicator", "1 if the document was issued for a limited duration.", F(1), N)],
+    },
+    "DDE" => ElementDefinition {
+        id: "DDE",
+        versions: &[required(4..=10, "Family Name Truncation", "T if the family name was truncated, N if not, or U if unknown.", F(1), A)],
+    },
+    "DDF" => ElementDefinition {
+        id: "DDF",
+        versions: &[required(4..=10, "First Name Truncation", "T if the first name was truncated, N if not, or U if unknown.", F(1), A)],
+    },
+    "DDG" => ElementDefinition {
+        id: "DDG",
+        versions: &[required(4..=10, "Middle Name Truncation", "T if the middle name was truncated, N if not, or U if unknown.", F(1), A)],
+    },
+    "DDH" => ElementDefinition {
+        id: "DDH",
+        versions: &[optional(4..=10, "Under 18 Until", "Date the cardholder turns 18.", F(8), N)],
+    },
+    "DDI" => ElementDefinition {
+        id: "DDI",
+        versions: &[optional(4..=10, "Under 19 Until", "Date the cardholder turns 19.", F(8), N)],
+    },
+    "DDJ" => ElementDefinition {
+        id: "DDJ",
+        versions: &[optional(4..=10, "Under 21 Until", "Date the cardholder turns 21.", F(8), N)],
+    },
+    "DDK" => ElementDefinition {
+        id: "DDK",
+        versions: &[optional(8..=10, "Organ Donor Indicator", "1 if the cardholder is an organ donor.", F(1), N)],
+    },
+    "DDL" => ElementDefinition {
+        id: "DDL",
+        versions: &[optional(8..=10, "Veteran Indicator", "1 if the cardholder is a veteran.", F(1), N)],
+    },
+};
+
+/// A piece of information about the cardholder or document, which different
+/// versions of the standard store in different elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldKind {
+    FullName,
+    FamilyName,
+    FirstName,
+    MiddleName,
+    /// First and middle names together, separated by a comma.
+    GivenNames,
+    NameSuffix,
+    NamePrefix,
+    AliasFamilyName,
+    AliasGivenName,
+    AliasSuffix,
+    FamilyNameTruncation,
+    FirstNameTruncation,
+    MiddleNameTruncation,
+    DocumentExpirationDate,
+    DocumentIssueDate,
+    DateOfBirth,
+    Sex,
+    EyeColor,
+    HairColor,
+    Height,
+    Weight,
+    Race,
+    AddressStreet1,
+    AddressStreet2,
+    AddressCity,
+    AddressJurisdictionCode,
+    AddressPostalCode,
+    ResidenceStreet1,
+    ResidenceStreet2,
+    ResidenceCity,
+    ResidenceJurisdictionCode,
+    ResidencePostalCode,
+    CustomerIdNumber,
+    DocumentDiscriminator,
+    Country,
+    PlaceOfBirth,
+    AuditInformation,
+    InventoryControlNumber,
+    VehicleClass,
+    Restrictions,
+    Endorsements,
+    StandardVehicleClass,
+    StandardRestrictions,
+    StandardEndorsements,
+    VehicleClassDescription,
+    RestrictionsDescription,
+    EndorsementsDescription,
+    FederalCommercialVehicleCodes,
+    ComplianceType,
+    CardRevisionDate,
+    HazmatEndorsementExpirationDate,
+    LimitedDurationDocument,
+    OrganDonor,
+    Veteran,
+    Under18Until,
+    Under19Until,
+    Under21Until,
+}
+
+impl FieldKind {
+    /// The elements that store this field in a version of the standard, in
+    /// the order they should be checked.
+    ///
+    /// Versions before 1 are treated as version 1 and versions after 10 as
+    /// version 10.
+    pub fn elements(self, version: u8) -> &'static [ElementId] {
+        let version = version.clamp(1, 10);
+
+        FIELD_MAP
+            .iter()
+            .find(|(field, versions, _)| *field == self && versions.contains(&version))
+            .map(|(_, _, elements)| *elements)
+            .unwrap_or_default()
+    }
+
+    /// The jurisdiction-specific element that stores this field on documents
+    /// from an issuer.
+    pub fn jurisdiction_element(self, issuer: IssuerIdentification) -> Option<&'static str> {
+        JURISDICTION_FIELD_MAP
+            .iter()
+            .find(|(field_issuer, field, _)| *field_issuer == issuer && *field == self)
+            .map(|(_, _, id)| *id)
+    }
+}
+
+/// Which jurisdiction-specific elements store a field for issuers that do
+/// not use the standard elements. Height is the only field that reads it.
+///
+/// Each element is in the `Z` subfile named by its second letter, so `ZIJ` is
+/// in subfile `ZI`.
+static JURISDICTION_FIELD_MAP: &[(IssuerIdentification, FieldKind, &str)] = {
+    use FieldKind as K;
+    use IssuerIdentification as I;
+
+    &[
+        // Height as feet and inches, such as `5-10`.
+        (I::Idaho, K::Height, "ZIJ"),
+    ]
+};
+
+/// Which elements store each field in each version of the standard.
+///
+/// Versions are 1 (2000), 2 (2003), 3 (2005), 4 (2009), 5 through 8 (2010 to
+/// 2013), 9 (2016), and 10 (2020).
+static FIELD_MAP: &[(FieldKind, RangeInclusive<u8>, &[ElementId])] = {
+    use ElementId as E;
+    use FieldKind as K;
+
+    &[
+        (K::FullName, 1..=1, &[E::FullName]),
+        (K::FamilyName, 1..=1, &[E::LastName]),
+        (K::FamilyName, 2..=10, &[E::FamilyName]),
+        (K::FirstName, 1..=1, &[E::FirstName]),
+        (K::FirstName, 4..=10, &[E::FirstName]),
+        (K::MiddleName, 1..=1, &[E::MiddleName]),
+        (K::MiddleName, 4..=10, &[E::MiddleName]),
+        (K::GivenNames, 2..=3, &[E::GivenNames]),
+        (K::NameSuffix, 1..=1, &[E::LegacyNameSuffix]),
+        (K::NameSuffix, 2..=10, &[E::NameSuffix]),
+        (K::NamePrefix, 1..=1, &[E::NamePrefix]),
+        (K::AliasFamilyName, 1..=1, &[E::AliasLastName]),
+        (K::AliasFamilyName, 2..=10, &[E::AliasFamilyName]),
+        (K::AliasGivenName, 1..=1, &[E::AliasFirstName]),
+        (K::AliasGivenName, 2..=10, &[E::AliasGivenName]),
+        (K::AliasSuffix, 1..=1, &[E::LegacyAliasNameSuffix]),
+        (K::AliasSuffix, 2..=10, &[E::AliasNameSuffix]),
+        (K::FamilyNameTruncation, 4..=10, &[E::FamilyNameTruncation]),
+        (K::FirstNameTruncation, 4..=10, &[E::FirstNameTruncation]),
+        (K::MiddleNameTruncation, 4..=10, &[E::MiddleNameTruncation]),
+        (
+            K::DocumentExpirationDate,
+            1..=10,
+            &[E::DocumentExpirationDate],
+        ),
+        (K::DocumentIssueDate, 1..=10, &[E::DocumentIssueDate]),
+        (K::DateOfBirth, 1..=10, &[E::DateOfBirth]),
+        (K::Sex, 1..=10, &[E::Sex]),
+        (K::EyeColor, 1..=10, &[E::EyeColor]),
+        (K::HairColor, 1..=10, &[E::HairColor]),
+        (K::Height, 1..=1, &[E::Height, E::HeightCentimeters]),
+        (K::Height, 2..=10, &[E::Height]),
+        (K::Weight, 1..=1, &[E::WeightPounds, E::WeightKilograms]),
+        (K::Weight, 2..=3, &[E::WeightRange]),
+        (
+            K::Weight,
+            4..=10,
+            &[E::WeightPounds, E::WeightKilograms, E::WeightRange],
+        ),
+        (K::Race, 2..=10, &[E::Race]),
+        (K::AddressStreet1, 1..=10, &[E::AddressStreet1]),
+        (K::AddressStreet2, 1..=10, &[E::AddressStreet2]),
+        (K::AddressCity, 1..=10, &[E::AddressCity]),
+        (
+            K::AddressJurisdictionCode,
+            1..=10,
+            &[E::AddressJurisdictionCode],
+        ),
+        (K::AddressPostalCode, 1..=10, &[E::AddressPostalCode]),
+        (K::ResidenceStreet1, 1..=1, &[E::ResidenceStreet1]),
+        (K::ResidenceStreet2, 1..=1, &[E::ResidenceStreet2]),
+        (K::ResidenceCity, 1..=1, &[E::ResidenceCity]),
+        (
+            K::ResidenceJurisdictionCode,
+            1..=1,
+            &[E::ResidenceJurisdictionCode],
+        ),
+        (K::ResidencePostalCode, 1..=1, &[E::ResidencePostalCode]),
+        (K::CustomerIdNumber, 1..=10, &[E::CustomerIdNumber]),
+        (
+            K::DocumentDiscriminator,
+            2..=10,
+            &[E::DocumentDiscriminator],
+        ),
+        (K::Country, 2..=10, &[E::Country]),
+        (K::PlaceOfBirth, 2..=10, &[E::PlaceOfBirth]),
+        (K::AuditInformation, 2..=10, &[E::AuditInformation]),
+        (
+            K::InventoryControlNumber,
+            2..=10,
+            &[E::InventoryControlNumber],
+        ),
+        (K::VehicleClass, 1..=1, &[E::LicenseClassification]),
+        (K::VehicleClass, 2..=10, &[E::VehicleClass]),
+        (K::Restrictions, 1..=1, &[E::LicenseRestrictions]),
+        (K::Restrictions, 2..=10, &[E::RestrictionCodes]),
+        (K::Endorsements, 1..=1, &[E::LicenseEndorsements]),
+        (K::Endorsements, 2..=10, &[E::EndorsementCodes]),
+        (
+            K::StandardVehicleClass,
+            2..=10,
+            &[E::StandardVehicleClassification],
+        ),
+        (
+            K::StandardRestrictions,
+            2..=10,
+            &[E::StandardRestrictionCode],
+        ),
+        (
+            K::StandardEndorsements,
+            2..=10,
+            &[E::StandardEndorsementCode],
+        ),
+        (
+            K::VehicleClassDescription,
+            2..=10,
+            &[E::VehicleClassDescription],
+        ),
+        (
+            K::RestrictionsDescription,
+            2..=10,
+            &[E::RestrictionCodeDescription],
+        ),
+        (
+            K::EndorsementsDescription,
+            2..=10,
+            &[E::EndorsementCodeDescription],
+        ),
+        (
+            K::FederalCommercialVehicleCodes,
+            2..=10,
+            &[E::FederalCommercialVehicleCodes],
+        ),
+        (K::ComplianceType, 4..=10, &[E::ComplianceType]),
+        (K::CardRevisionDate, 4..=10, &[E::CardRevisionDate]),
+        (
+            K::HazmatEndorsementExpirationDate,
+            4..=10,
+            &[E::HazmatEndorsementExpirationDate],
+        ),
+        (
+            K::LimitedDurationDocument,
+            4..=10,
+            &[E::LimitedDurationDocument],
+        ),
+        (K::OrganDonor, 1..=1, &[E::LegacyOrganDonor]),
+        (K::OrganDonor, 8..=10, &[E::OrganDonor]),
+        (K::Veteran, 8..=10, &[E::Veteran]),
+        (K::Under18Until, 4..=10, &[E::Under18Until]),
+        (K::Under19Until, 4..=10, &[E::Under19Until]),
+        (K::Under21Until, 4..=10, &[E::Under21Until]),
+    ]
+};
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        BarcodeBuilder, SubfileType,
+        data::{Height, IssuerCountry, Weight},
+        tests::parse_elements,
+    };
+
+    #[test]
+    fn test_jurisdiction_fields() {
+        for (issuer_id, expected) in [(636050, Some(Height::Inches(70))), (636000, None)] {
+            let payload = BarcodeBuilder::new(issuer_id, 3)
+                .element(SubfileType::DL, "DAQ", "1")
+                .element(SubfileType::JurisdictionSpecific('I'), "ZIJ", "5-10")
+                .build()
+                .unwrap();
+            let data = crate::parse_barcode_bytes(&payload).unwrap();
+            assert_eq!(data.height(), expected, "{issuer_id}");
+        }
+    }
+
+    #[test]
+    fn test_version_mapping() {
+        let cases = [
+            (
+                1..=1,
+                vec![
+                    ("DAB", "SAMPLE"),
+                    ("DAC", "JOHN"),
+                    ("DAD", "Q"),
+                    ("DAE", "JR"),
+                    ("DAU", "510"),
+                    ("DAW", "180"),
+                    ("DCF", "X"),
+                    ("DDJ", "20100101"),
+                ],
+                (
+                    Some("JR"),
+                    Some(Height::Inches(70)),
+                    Some(Weight::Pounds(180)),
+                    None,
+                    Some(IssuerCountry::UnitedStates),
+                    None,
+                ),
+            ),
+            (
+                2..=3,
+                vec![
+                    ("DCS", "SAMPLE"),
+                    ("DCT", "JOHN,Q"),
+                    ("DCU", "JR"),
+                    ("DAU", "070 in"),
+                    ("DAW", "180"),
+                    ("DCE", "4"),
+                    ("DCF", "X"),
+                    ("DCG", "CAN"),
+                    ("DDJ", "01012010"),
+                ],
+                (
+                    Some("JR"),
+                    Some(Height::Inches(70)),
+                    Some(Weight::KilogramRange { from: 71, to: 86 }),
+                    Some("X"),
+                    Some(IssuerCountry::Canada),
+                    None,
+                ),
+            ),
+            (
+                4..=10,
+                vec![
+                    ("DCS", "SAMPLE"),
+                    ("DAC", "JOHN"),
+                    ("DAD", "Q"),
+                    ("DCT", "IGNORED"),
+                    ("DAU", "175 cm"),
+                    ("DAW", "180"),
+                    ("DCF", "X"),
+                    ("DCG", "USA"),
+                    ("DDJ", "01012010"),
+                ],
+                (
+                    None,
+                    Some(Height::Centimeters(175)),
+                    Some(Weight::Pounds(180)),
+                    Some("X"),
+                    Some(IssuerCountry::UnitedStates),
+                    Some(jiff::civil::date(2010, 1, 1)),
+                ),
+            ),
+        ];
+
+        for (versions, elements, expected) in cases {
+            for version in versions {
+                let data = parse_elements(636000, version, &elements);
+
+                let name = data.name().unwrap();
+                assert_eq!(
+                    (
+                        name.family.as_str(),
+                        name.first.as_str(),
+                        name.middle.as_deref()
+                    ),
+                    ("SAMPLE", "JOHN", Some("Q")),
+                    "version {version}"
+                );
+
+                let (suffix, height, weight, discriminator, country, under_21) = expected;
+                assert_eq!(name.suffix.as_deref(), suffix, "version {version}");
+                assert_eq!(data.height(), height, "version {version}");
+                assert_eq!(data.weight(), weight, "version {version}");
+                assert_eq!(
+                    data.document_discriminator().as_deref(),
+                    discriminator,
+                    "version {version}"
+                );
+                assert_eq!(data.country(), country, "version {version}");
+                assert_eq!(
+                    data.under_age_until().under_21_until,
+                    under_21,
+                    "version {version}"
+                );
+            }
+        }
+        // Three-byte heights that are not ASCII must not be sliced mid-character.
+        for height in ["\u{e9}5", "5\u{e9}"] {
+            let data = parse_elements(636000, 1, &[("DAU", height)]);
+            assert_eq!(data.height(), None, "{height}");
+        }
+    }
+}
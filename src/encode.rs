@@ -0,0 +1,808 @@
+use jiff::civil::Date;
+use serde::Serialize;
+
+use crate::{
+    Data, DecodedData, ElementId, SUBFILE_DESIGNATOR_LENGTH, Separators, SubfileType,
+    data::{
+        Address, ComplianceType, DateFormat, Endorsement, Expiration, EyeColor, HairColor, Height,
+        IssuerCountry, Name, Privileges, Race, Restriction, Sex, Truncation, VehicleClass, Weight,
+    },
+    elements::FieldKind,
+};
+
+/// Builds the payload of an AAMVA barcode from a set of elements.
+///
+/// The header, including every subfile designator, is computed from the
+/// subfiles added to the builder. Subfiles are written in the order they were
+/// first added and elements in the order they were added to their subfile.
+///
+/// ```
+/// use aamva::{BarcodeBuilder, SubfileType};
+///
+/// let payload = BarcodeBuilder::new(636000, 10)
+///     .element(SubfileType::DL, "DAQ", "T64235789")
+///     .element(SubfileType::DL, "DCS", "SAMPLE")
+///     .build()
+///     .unwrap();
+///
+/// let data = aamva::parse_barcode_bytes(&payload).unwrap();
+/// assert_eq!(data.subfiles[&SubfileType::DL]["DCS"].as_deref(), Some("SAMPLE"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BarcodeBuilder {
+    separators: Separators,
+    issuer_id: u32,
+    version_number: u8,
+    jurisdiction_version_number: u8,
+    subfiles: Vec<(SubfileType, Vec<(String, String)>)>,
+}
+
+impl BarcodeBuilder {
+    /// Start a barcode for the given issuer identification number and AAMVA
+    /// version number.
+    pub fn new(issuer_id: u32, version_number: u8) -> Self {
+        Self {
+            separators: Separators::default(),
+            issuer_id,
+            version_number,
+            jurisdiction_version_number: 0,
+            subfiles: Vec::new(),
+        }
+    }
+
+    /// Start a barcode with the same header values and elements as parsed
+    /// data, keeping the original order and untrimmed values of elements.
+    pub fn from_data(data: &Data<'_>) -> Self {
+        let header = &data.header;
+
+        let mut builder = Self::new(header.issuer_id, header.version_number)
+            .separators(header.separators)
+            .jurisdiction_version_number(header.jurisdiction_version_number.unwrap_or_default());
+
+        for subfile in &data.raw.subfiles {
+            builder.subfiles.push((
+                subfile.designator.subfile_type,
+                subfile
+                    .elements
+                    .iter()
+                    .map(|elem| {
+                        (
+                            elem.id.to_string(),
+                            elem.decoded_value(data.raw.encoding).into_owned(),
+                        )
+                    })
+                    .collect(),
+            ));
+        }
+
+        builder
+    }
+
+    /// Start a barcode from decoded data, writing each field to a subfile of
+    /// the given type using the elements of `aamva_version`.
+    ///
+    /// This is the inverse of decoding, so the barcode decodes back into the
+    /// same data. Fields that the version has no element for are left out.
+    pub fn from_decoded(data: &DecodedData, subfile_type: SubfileType) -> Self {
+        let version = data.aamva_version;
+
+        let date_format = DateFormat::for_issuer(data.issuer_id, version);
+        let date = |date: &Date| date_format.format(date.year(), date.month(), date.day());
+
+        let mut elements = Elements {
+            version,
+            elements: Vec::new(),
+        };
+
+        if let Some(name) = &data.name {
+            encode_name(name, &mut elements);
+        }
+
+        let dates = [
+            (
+                FieldKind::DocumentExpirationDate,
+                &data.document_expiration_date,
+            ),
+            (FieldKind::DateOfBirth, &data.date_of_birth),
+            (FieldKind::DocumentIssueDate, &data.document_issue_date),
+            (FieldKind::CardRevisionDate, &data.card_revision_date),
+            (
+                FieldKind::HazmatEndorsementExpirationDate,
+                &data.commercial.hazmat_endorsement_expiration_date,
+            ),
+            (
+                FieldKind::Under18Until,
+                &data.under_age_until.under_18_until,
+            ),
+            (
+                FieldKind::Under19Until,
+                &data.under_age_until.under_19_until,
+            ),
+            (
+                FieldKind::Under21Until,
+                &data.under_age_until.under_21_until,
+            ),
+        ];
+
+        for (kind, value) in dates {
+            if let Some(value) = value {
+                elements.push(kind, date(value));
+            }
+        }
+
+        if data.date_of_birth.is_none()
+            && let Some(partial) = &data.partial_date_of_birth
+        {
+            let value = date_format.format(
+                partial.year,
+                partial.month.unwrap_or(0),
+                partial.day.unwrap_or(0),
+            );
+            elements.push(FieldKind::DateOfBirth, value);
+        }
+
+        if data.document_expiration_date.is_none() && data.expiration == Expiration::NonExpiring {
+            elements.push(FieldKind::DocumentExpirationDate, "99999999".to_string());
+        }
+
+        if let Some(sex) = &data.sex {
+            let sex = match (sex, version) {
+                (Sex::Male, ..=1) => "M",
+                (Sex::Female, ..=1) => "F",
+                (Sex::Male, _) => "1",
+                (Sex::Female, _) => "2",
+                (Sex::NotSpecified, _) => "9",
+            };
+            elements.push(FieldKind::Sex, sex.to_string());
+        }
+
+        if let Some(eye_color) = data.eye_color {
+            elements.push(FieldKind::EyeColor, eye_color_code(eye_color).to_string());
+        }
+
+        if let Some(hair_color) = &data.hair_color {
+            elements.push(
+                FieldKind::HairColor,
+                hair_color_code(hair_color).to_string(),
+            );
+        }
+
+        match (data.height, version) {
+            (Some(Height::Inches(inches)), ..=1) => elements.push_id(
+                ElementId::Height,
+                format!("{}{:02}", inches / 12, inches % 12),
+            ),
+            (Some(Height::Centimeters(centimeters)), ..=1) => {
+                elements.push_id(ElementId::HeightCentimeters, centimeters.to_string());
+            }
+            (Some(Height::Inches(inches)), _) => {
+                elements.push_id(ElementId::Height, format!("{inches:03} in"));
+            }
+            (Some(Height::Centimeters(centimeters)), _) => {
+                elements.push_id(ElementId::Height, format!("{centimeters:03} cm"));
+            }
+            (None, _) => (),
+        }
+
+        if let Some(weight) = data.weight {
+            let (id, value) = match weight {
+                Weight::Pounds(pounds) => (ElementId::WeightPounds, pounds.to_string()),
+                Weight::Kilograms(kilograms) => (ElementId::WeightKilograms, kilograms.to_string()),
+                Weight::KilogramRange { from, .. } => {
+                    (ElementId::WeightRange, weight_range_code(from).to_string())
+                }
+            };
+
+            if FieldKind::Weight.elements(version).contains(&id) {
+                elements.push_id(id, value);
+            }
+        }
+
+        if let Some(race) = &data.race {
+            elements.push(FieldKind::Race, race_code(race).to_string());
+        }
+
+        if let Some(address) = &data.address {
+            encode_address(
+                address,
+                [
+                    FieldKind::AddressStreet1,
+                    FieldKind::AddressStreet2,
+                    FieldKind::AddressCity,
+                    FieldKind::AddressJurisdictionCode,
+                    FieldKind::AddressPostalCode,
+                ],
+                &mut elements,
+            );
+        }
+
+        if let Some(address) = &data.residence_address {
+            encode_address(
+                address,
+                [
+                    FieldKind::ResidenceStreet1,
+                    FieldKind::ResidenceStreet2,
+                    FieldKind::ResidenceCity,
+                    FieldKind::ResidenceJurisdictionCode,
+                    FieldKind::ResidencePostalCode,
+                ],
+                &mut elements,
+            );
+        }
+
+        // Version 1 has no country element, so the country is implied by the
+        // height instead.
+        if let Some(country) = data.country {
+            let country = match country {
+                IssuerCountry::UnitedStates => "USA",
+                IssuerCountry::Canada => "CAN",
+                IssuerCountry::Mexico => "MEX",
+            };
+            elements.push(FieldKind::Country, country.to_string());
+        }
+
+        let compliance_type = match &data.compliance_type {
+            Some(ComplianceType::FullyCompliant) => Some("F"),
+            Some(ComplianceType::NonCompliant) => Some("N"),
+            Some(ComplianceType::Other(compliance_type)) => Some(compliance_type.as_str()),
+            Some(ComplianceType::Legacy) | None => None,
+        };
+
+        if let Some(compliance_type) = compliance_type {
+            elements.push(FieldKind::ComplianceType, compliance_type.to_string());
+        }
+
+        let indicators = [
+            (FieldKind::OrganDonor, data.organ_donor),
+            (FieldKind::Veteran, data.veteran),
+            (
+                FieldKind::LimitedDurationDocument,
+                data.limited_duration_document,
+            ),
+        ];
+
+        for (kind, value) in indicators {
+            let value = match (value, version) {
+                (Some(true), ..=1) => "Y",
+                (Some(false), ..=1) => "N",
+                (Some(true), _) => "1",
+                (Some(false), _) => "0",
+                (None, _) => continue,
+            };
+            elements.push(kind, value.to_string());
+        }
+
+        if let Some(privileges) = &data.privileges {
+            encode_privileges(privileges, &mut elements);
+        }
+
+        let strings = [
+            (FieldKind::CustomerIdNumber, &data.customer_id_number),
+            (
+                FieldKind::DocumentDiscriminator,
+                &data.document_discriminator,
+            ),
+            (FieldKind::PlaceOfBirth, &data.place_of_birth),
+            (
+                FieldKind::FederalCommercialVehicleCodes,
+                &data.commercial.federal_commercial_vehicle_codes,
+            ),
+            (FieldKind::AuditInformation, &data.audit_information),
+            (
+                FieldKind::InventoryControlNumber,
+                &data.inventory_control_information,
+            ),
+        ];
+
+        for (kind, value) in strings {
+            if let Some(value) = value {
+                elements.push(kind, value.clone());
+            }
+        }
+
+        elements.elements.into_iter().fold(
+            Self::new(data.issuer_id, version)
+                .jurisdiction_version_number(data.jurisdiction_version.unwrap_or_default()),
+            |builder, (id, value)| builder.element(subfile_type, id.as_str(), value),
+        )
+    }
+
+    /// Use different separators than the standard ones.
+    pub fn separators(mut self, separators: Separators) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    /// Set the jurisdiction version number, which is only written for AAMVA
+    /// versions after 2.
+    pub fn jurisdiction_version_number(mut self, jurisdiction_version_number: u8) -> Self {
+        self.jurisdiction_version_number = jurisdiction_version_number;
+        self
+    }
+
+    /// Add an element to the end of a subfile, creating the subfile if it does
+    /// not exist yet.
+    pub fn element(
+        mut self,
+        subfile_type: SubfileType,
+        id: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        let element = (id.into(), value.into());
+
+        match self
+            .subfiles
+            .iter_mut()
+            .find(|(existing, _)| *existing == subfile_type)
+        {
+            Some((_, elements)) => elements.push(element),
+            None => self.subfiles.push((subfile_type, vec![element])),
+        }
+
+        self
+    }
+
+    /// Encode the barcode payload.
+    ///
+    /// Values are written in ISO-8859-1, the only character set the standard
+    /// allows besides ASCII.
+    pub fn build(&self) -> Result<Vec<u8>, EncodeError> {
+        let separators = self.separators;
+
+        if ![
+            separators.data_element,
+            separators.record,
+            separators.segment_terminator,
+        ]
+        .iter()
+        .all(|b| b.is_ascii() && !b.is_ascii_alphanumeric())
+        {
+            return Err(EncodeError::InvalidSeparators);
+        }
+
+        if self.issuer_id > 999_999 {
+            return Err(EncodeError::InvalidIssuerNumber {
+                issuer_id: self.issuer_id,
+            });
+        }
+
+        if self.version_number > 99 || self.jurisdiction_version_number > 99 {
+            return Err(EncodeError::InvalidVersion);
+        }
+
+        if self.subfiles.len() > 99 {
+            return Err(EncodeError::TooManySubfiles {
+                count: self.subfiles.len(),
+            });
+        }
+
+        let subfiles = self
+            .subfiles
+            .iter()
+            .map(|(subfile_type, elements)| {
+                encode_subfile(*subfile_type, elements, &self.separators)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut header = format!(
+            "@{}{}{}ANSI {:06}{:02}",
+            char::from(separators.data_element),
+            char::from(separators.record),
+            char::from(separators.segment_terminator),
+            self.issuer_id,
+            self.version_number
+        );
+
+        if self.version_number > 2 {
+            header.push_str(&format!("{:02}", self.jurisdiction_version_number));
+        }
+
+        header.push_str(&format!("{:02}", subfiles.len()));
+
+        let mut offset = header.len() + subfiles.len() * SUBFILE_DESIGNATOR_LENGTH;
+
+        for ((subfile_type, _), subfile) in self.subfiles.iter().zip(&subfiles) {
+            if offset > 9999 || subfile.len() > 9999 {
+                return Err(EncodeError::TooLong {
+                    subfile_type: *subfile_type,
+                });
+            }
+
+            header.push_str(&format!("{subfile_type}{offset:04}{:04}", subfile.len()));
+            offset += subfile.len();
+        }
+
+        let mut payload = header.into_bytes();
+        payload.extend(subfiles.concat());
+
+        Ok(payload)
+    }
+}
+
+/// Elements for a subfile, written with the IDs of a version.
+struct Elements {
+    version: u8,
+    elements: Vec<(ElementId, String)>,
+}
+
+impl Elements {
+    /// Write a field to the first element the version stores it in, if any.
+    fn push(&mut self, kind: FieldKind, value: String) {
+        if let Some(id) = kind.elements(self.version).first() {
+            self.elements.push((id.clone(), value));
+        }
+    }
+
+    fn push_id(&mut self, id: ElementId, value: String) {
+        self.elements.push((id, value));
+    }
+}
+
+fn encode_name(name: &Name, elements: &mut Elements) {
+    let version = elements.version;
+
+    let given = |separator| {
+        std::iter::once(name.first.as_str())
+            .chain(name.middle.as_deref())
+            .collect::<Vec<_>>()
+            .join(separator)
+    };
+
+    let has_full_name = !FieldKind::FullName.elements(version).is_empty();
+    let has_first_name = !FieldKind::FirstName.elements(version).is_empty();
+
+    // The full name element has no room for a prefix or suffix, so versions
+    // that also have separate name elements use those instead.
+    if has_full_name && name.prefix.is_none() && name.suffix.is_none() {
+        elements.push(
+            FieldKind::FullName,
+            format!("{},{}", name.family, given(",")),
+        );
+    } else if has_first_name {
+        elements.push(FieldKind::FamilyName, name.family.clone());
+        elements.push(FieldKind::FirstName, name.first.clone());
+        if let Some(middle) = &name.middle {
+            elements.push(FieldKind::MiddleName, middle.clone());
+        }
+    } else {
+        elements.push(FieldKind::FamilyName, name.family.clone());
+        elements.push(FieldKind::GivenNames, given(","));
+    }
+
+    let optional = [
+        (FieldKind::NameSuffix, &name.suffix),
+        (FieldKind::NamePrefix, &name.prefix),
+        (FieldKind::AliasFamilyName, &name.alias_family),
+        (FieldKind::AliasGivenName, &name.alias_given),
+        (FieldKind::AliasSuffix, &name.alias_suffix),
+    ];
+
+    for (kind, value) in optional {
+        if let Some(value) = value {
+            elements.push(kind, value.clone());
+        }
+    }
+
+    let truncations = [
+        (FieldKind::FamilyNameTruncation, &name.family_truncation),
+        (FieldKind::FirstNameTruncation, &name.first_truncation),
+        (FieldKind::MiddleNameTruncation, &name.middle_truncation),
+    ];
+
+    for (kind, truncation) in truncations {
+        if let Some(truncation) = truncation {
+            let truncation = match truncation {
+                Truncation::Truncated => "T",
+                Truncation::NotTruncated => "N",
+                Truncation::Unknown => "U",
+            };
+            elements.push(kind, truncation.to_string());
+        }
+    }
+}
+
+fn encode_address(
+    address: &Address,
+    [street_1, street_2, city, jurisdiction_code, postal_code]: [FieldKind; 5],
+    elements: &mut Elements,
+) {
+    elements.push(street_1, address.address_1.clone());
+    if let Some(address_2) = &address.address_2 {
+        elements.push(street_2, address_2.clone());
+    }
+    elements.push(city, address.city.clone());
+    elements.push(jurisdiction_code, address.jurisdiction_code.clone());
+    elements.push(postal_code, address.postal_code.to_element());
+}
+
+fn encode_privileges(privileges: &Privileges, elements: &mut Elements) {
+    // Codes are separated by spaces so codes longer than one letter are read
+    // back as one code.
+    let codes = [
+        (
+            FieldKind::VehicleClass,
+            privileges
+                .vehicle_classes
+                .iter()
+                .map(vehicle_class_code)
+                .collect::<Vec<_>>(),
+        ),
+        (
+            FieldKind::Restrictions,
+            privileges
+                .restrictions
+                .iter()
+                .map(restriction_code)
+                .collect(),
+        ),
+        (
+            FieldKind::Endorsements,
+            privileges
+                .endorsements
+                .iter()
+                .map(endorsement_code)
+                .collect(),
+        ),
+        (
+            FieldKind::StandardVehicleClass,
+            privileges
+                .standard_vehicle_classes
+                .iter()
+                .map(vehicle_class_code)
+                .collect(),
+        ),
+        (
+            FieldKind::StandardRestrictions,
+            privileges
+                .standard_restrictions
+                .iter()
+                .map(restriction_code)
+                .collect(),
+        ),
+        (
+            FieldKind::StandardEndorsements,
+            privileges
+                .standard_endorsements
+                .iter()
+                .map(endorsement_code)
+                .collect(),
+        ),
+    ];
+
+    for (kind, codes) in codes {
+        if !codes.is_empty() {
+            elements.push(kind, codes.join(" "));
+        }
+    }
+
+    let descriptions = [
+        (
+            FieldKind::VehicleClassDescription,
+            &privileges.vehicle_class_description,
+        ),
+        (
+            FieldKind::RestrictionsDescription,
+            &privileges.restrictions_description,
+        ),
+        (
+            FieldKind::EndorsementsDescription,
+            &privileges.endorsements_description,
+        ),
+    ];
+
+    for (kind, description) in descriptions {
+        if let Some(description) = description {
+            elements.push(kind, description.clone());
+        }
+    }
+}
+
+fn vehicle_class_code(vehicle_class: &VehicleClass) -> &str {
+    match vehicle_class {
+        VehicleClass::A => "A",
+        VehicleClass::B => "B",
+        VehicleClass::C => "C",
+        VehicleClass::M => "M",
+        VehicleClass::Other(code) => code,
+    }
+}
+
+fn restriction_code(restriction: &Restriction) -> &str {
+    match restriction {
+        Restriction::CorrectiveLenses => "B",
+        Restriction::MechanicalAid => "C",
+        Restriction::ProstheticAid => "D",
+        Restriction::AutomaticTransmission => "E",
+        Restriction::OutsideMirror => "F",
+        Restriction::DaylightOnly => "G",
+        Restriction::EmploymentOnly => "H",
+        Restriction::LimitedOther => "I",
+        Restriction::OtherRestriction => "J",
+        Restriction::IntrastateOnly => "K",
+        Restriction::NoAirBrakes => "L",
+        Restriction::NoClassAPassengerVehicles => "M",
+        Restriction::NoClassAOrBPassengerVehicles => "N",
+        Restriction::NoTractorTrailer => "O",
+        Restriction::NoPassengers => "P",
+        Restriction::MedicalVariance => "V",
+        Restriction::FarmWaiver => "W",
+        Restriction::EmptyTankVehiclesOnly => "X",
+        Restriction::NoFullAirBrakes => "Z",
+        Restriction::Other(code) => code,
+    }
+}
+
+fn endorsement_code(endorsement: &Endorsement) -> &str {
+    match endorsement {
+        Endorsement::HazardousMaterials => "H",
+        Endorsement::TankVehicle => "N",
+        Endorsement::PassengerVehicle => "P",
+        Endorsement::SchoolBus => "S",
+        Endorsement::DoubleTripleTrailers => "T",
+        Endorsement::TankVehicleHazardousMaterials => "X",
+        Endorsement::Other(code) => code,
+    }
+}
+
+fn eye_color_code(eye_color: EyeColor) -> &'static str {
+    match eye_color {
+        EyeColor::Black => "BLK",
+        EyeColor::Blue => "BLU",
+        EyeColor::Brown => "BRO",
+        EyeColor::Dichromatic => "DIC",
+        EyeColor::Green => "GRN",
+        EyeColor::Gray => "GRY",
+        EyeColor::Hazel => "HAZ",
+        EyeColor::Maroon => "MAR",
+        EyeColor::Pink => "PNK",
+        EyeColor::Unknown => "UNK",
+    }
+}
+
+fn hair_color_code(hair_color: &HairColor) -> &'static str {
+    match hair_color {
+        HairColor::Bald => "BAL",
+        HairColor::Black => "BLK",
+        HairColor::Blond => "BLN",
+        HairColor::Brown => "BRO",
+        HairColor::Gray => "GRY",
+        HairColor::RedAuburn => "RED",
+        HairColor::Sandy => "SDY",
+        HairColor::White => "WHI",
+        HairColor::Unknown => "UNK",
+    }
+}
+
+fn race_code(race: &Race) -> &'static str {
+    match race {
+        Race::AlaskanAmericanIndian => "AI",
+        Race::AsianPacificIslander => "AP",
+        Race::Black => "BK",
+        Race::HispanicOrigin => "H",
+        Race::NonHispanic => "O",
+        Race::Unknown => "U",
+        Race::White => "W",
+    }
+}
+
+fn weight_range_code(from: u8) -> u8 {
+    match from {
+        ..=31 => 0,
+        32..=45 => 1,
+        46..=59 => 2,
+        60..=70 => 3,
+        71..=86 => 4,
+        87..=100 => 5,
+        101..=113 => 6,
+        114..=127 => 7,
+        128..=145 => 8,
+        146.. => 9,
+    }
+}
+
+fn encode_subfile(
+    subfile_type: SubfileType,
+    elements: &[(String, String)],
+    separators: &Separators,
+) -> Result<Vec<u8>, EncodeError> {
+    // Only the standard subfiles start with their type, which is how the
+    // parser reads them back.
+    let mut subfile = match subfile_type {
+        SubfileType::DL | SubfileType::EN | SubfileType::ID => {
+            subfile_type.to_string().into_bytes()
+        }
+        SubfileType::JurisdictionSpecific(_) | SubfileType::Unknown(..) => Vec::new(),
+    };
+
+    // A subfile without elements still needs to be terminated.
+    if elements.is_empty() {
+        subfile.push(separators.segment_terminator);
+    }
+
+    for (index, (id, value)) in elements.iter().enumerate() {
+        if id.len() != 3 || !id.bytes().all(|b| b.is_ascii_uppercase()) {
+            return Err(EncodeError::InvalidElementId { id: id.clone() });
+        }
+
+        let value =
+            encode_latin1(value).map_err(|character| EncodeError::UnencodableCharacter {
+                id: id.clone(),
+                character,
+            })?;
+
+        if value.iter().any(|&b| separators.is_terminator(b)) {
+            return Err(EncodeError::InvalidValue { id: id.clone() });
+        }
+
+        let terminator = if index == elements.len() - 1 {
+            separators.segment_terminator
+        } else {
+            separators.data_element
+        };
+
+        subfile.extend(id.bytes());
+        subfile.extend(value);
+        subfile.push(terminator);
+    }
+
+    Ok(subfile)
+}
+
+/// Encode a value in ISO-8859-1, returning the first character that has no
+/// encoding.
+fn encode_latin1(value: &str) -> Result<Vec<u8>, char> {
+    value
+        .chars()
+        .map(|c| u8::try_from(c).map_err(|_| c))
+        .collect()
+}
+
+/// An error encountered while encoding a barcode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EncodeError {
+    /// A separator was not an ASCII punctuation or control character.
+    InvalidSeparators,
+    /// The issuer identification number was longer than six digits.
+    InvalidIssuerNumber { issuer_id: u32 },
+    /// The AAMVA or jurisdiction version number was longer than two digits.
+    InvalidVersion,
+    /// There were more subfiles than fit in the header.
+    TooManySubfiles { count: usize },
+    /// The offset or length of a subfile did not fit in four digits.
+    TooLong { subfile_type: SubfileType },
+    /// An element ID was not three uppercase letters.
+    InvalidElementId { id: String },
+    /// An element value contained a separator.
+    InvalidValue { id: String },
+    /// An element value contained a character that is not in ISO-8859-1.
+    UnencodableCharacter { id: String, character: char },
+}
+
+impl std::fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidSeparators => write!(f, "separators must be ASCII symbols"),
+            Self::InvalidIssuerNumber { issuer_id } => {
+                write!(f, "invalid issuer identification number {issuer_id}")
+            }
+            Self::InvalidVersion => write!(f, "version numbers must be at most two digits"),
+            Self::TooManySubfiles { count } => {
+                write!(f, "{count} subfiles is more than the header can hold")
+            }
+            Self::TooLong { subfile_type } => {
+                write!(f, "subfile {subfile_type} did not fit in the barcode")
+            }
+            Self::InvalidElementId { id } => write!(f, "invalid element ID {id:?}"),
+            Self::InvalidValue { id } => {
+                write!(f, "value of element {id} contained a separator")
+            }
+            Self::UnencodableCharacter { id, character } => write!(
+                f,
+                "value of element {id} contained {character:?}, which is not in ISO-8859-1"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
@@ -0,0 +1,221 @@
+use nom::error::{ContextError, ErrorKind};
+use serde::Serialize;
+
+use crate::{Diagnostic, SubfileType};
+
+/// An error encountered while parsing a barcode.
+///
+/// Every variant carries the byte offset into the original input where the
+/// problem was found and the label of the field that was being parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ParseError {
+    /// The `@` compliance indicator that starts every barcode was not found,
+    /// so the input is most likely not an AAMVA barcode.
+    MissingComplianceIndicator {
+        offset: usize,
+        context: &'static str,
+    },
+    /// The file type was not `ANSI ` or `AAMVA`.
+    InvalidFileType {
+        offset: usize,
+        context: &'static str,
+    },
+    /// The issuer identification number was not six digits.
+    InvalidIssuerNumber {
+        offset: usize,
+        context: &'static str,
+    },
+    /// The AAMVA or jurisdiction version number was not two digits.
+    InvalidVersion {
+        offset: usize,
+        context: &'static str,
+    },
+    /// The number of entries was not two digits.
+    InvalidNumberOfEntries {
+        offset: usize,
+        context: &'static str,
+    },
+    /// The input ended before all subfile designators could be read, usually
+    /// because the scanner cut the read short.
+    TruncatedSubfileDesignator {
+        offset: usize,
+        context: &'static str,
+    },
+    /// A subfile designator was present but its type, offset, or length could
+    /// not be read.
+    MalformedSubfileDesignator {
+        offset: usize,
+        context: &'static str,
+    },
+    /// A subfile designator pointed past the end of the input.
+    SubfileOutOfBounds {
+        subfile_type: SubfileType,
+        offset: usize,
+        context: &'static str,
+    },
+    /// Strict parsing rejected something the lenient parser would have worked
+    /// around.
+    Nonconforming {
+        offset: usize,
+        context: &'static str,
+        diagnostic: Box<Diagnostic>,
+    },
+    /// The input was malformed in a way not covered by another variant.
+    Malformed {
+        offset: usize,
+        context: &'static str,
+    },
+}
+
+impl ParseError {
+    /// The byte offset into the original input where parsing failed.
+    pub fn offset(&self) -> usize {
+        match self {
+            Self::MissingComplianceIndicator { offset, .. }
+            | Self::InvalidFileType { offset, .. }
+            | Self::InvalidIssuerNumber { offset, .. }
+            | Self::InvalidVersion { offset, .. }
+            | Self::InvalidNumberOfEntries { offset, .. }
+            | Self::TruncatedSubfileDesignator { offset, .. }
+            | Self::MalformedSubfileDesignator { offset, .. }
+            | Self::SubfileOutOfBounds { offset, .. }
+            | Self::Nonconforming { offset, .. }
+            | Self::Malformed { offset, .. } => *offset,
+        }
+    }
+
+    /// The label of the field that was being parsed.
+    pub fn context(&self) -> &'static str {
+        match self {
+            Self::MissingComplianceIndicator { context, .. }
+            | Self::InvalidFileType { context, .. }
+            | Self::InvalidIssuerNumber { context, .. }
+            | Self::InvalidVersion { context, .. }
+            | Self::InvalidNumberOfEntries { context, .. }
+            | Self::TruncatedSubfileDesignator { context, .. }
+            | Self::MalformedSubfileDesignator { context, .. }
+            | Self::SubfileOutOfBounds { context, .. }
+            | Self::Nonconforming { context, .. }
+            | Self::Malformed { context, .. } => context,
+        }
+    }
+
+    /// Convert an error from the nom parsers, where `input` is the original
+    /// input that was passed to the parser.
+    pub(crate) fn from_nom(input: &[u8], err: nom::Err<NomError<'_>>) -> Self {
+        let err = match err {
+            nom::Err::Error(err) | nom::Err::Failure(err) => err,
+            nom::Err::Incomplete(_) => {
+                return Self::Malformed {
+                    offset: input.len(),
+                    context: "input",
+                };
+            }
+        };
+
+        if let Some(rejection) = err.rejection {
+            return *rejection;
+        }
+
+        let offset = input.len() - err.input.len();
+        let context = err.context.unwrap_or("input");
+
+        match context {
+            "compliance indicator" => Self::MissingComplianceIndicator { offset, context },
+            "record separator" | "file type" => Self::InvalidFileType { offset, context },
+            "issuer identification number" => Self::InvalidIssuerNumber { offset, context },
+            "aamva version number" | "jurisdiction version number" => {
+                Self::InvalidVersion { offset, context }
+            }
+            "number of entries" => Self::InvalidNumberOfEntries { offset, context },
+            "subfile designators" | "subfile type" | "subfile offset" | "subfile length"
+                if err.kind == ErrorKind::Eof =>
+            {
+                Self::TruncatedSubfileDesignator { offset, context }
+            }
+            "subfile designators" | "subfile type" | "subfile offset" | "subfile length" => {
+                Self::MalformedSubfileDesignator { offset, context }
+            }
+            _ => Self::Malformed { offset, context },
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let offset = self.offset();
+        let context = self.context();
+
+        match self {
+            Self::MissingComplianceIndicator { .. } => {
+                write!(f, "missing {context} at byte {offset}")
+            }
+            Self::InvalidFileType { .. }
+            | Self::InvalidIssuerNumber { .. }
+            | Self::InvalidVersion { .. }
+            | Self::InvalidNumberOfEntries { .. } => {
+                write!(f, "invalid {context} at byte {offset}")
+            }
+            Self::TruncatedSubfileDesignator { .. } => {
+                write!(f, "input ended while reading {context} at byte {offset}")
+            }
+            Self::SubfileOutOfBounds { subfile_type, .. } => write!(
+                f,
+                "{context} for subfile {subfile_type} was out of bounds at byte {offset}"
+            ),
+            Self::Nonconforming { diagnostic, .. } => {
+                write!(f, "nonconforming {context} at byte {offset}: {diagnostic}")
+            }
+            Self::MalformedSubfileDesignator { .. } | Self::Malformed { .. } => {
+                write!(f, "malformed {context} at byte {offset}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Error type used by the nom parsers, keeping the innermost context label so
+/// it can be converted into a [`ParseError`].
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct NomError<'a> {
+    pub(crate) input: &'a [u8],
+    pub(crate) kind: ErrorKind,
+    pub(crate) context: Option<&'static str>,
+    /// Set when parsing was stopped by a strict conformance check.
+    pub(crate) rejection: Option<Box<ParseError>>,
+}
+
+impl<'a> nom::error::ParseError<&'a [u8]> for NomError<'a> {
+    fn from_error_kind(input: &'a [u8], kind: ErrorKind) -> Self {
+        Self {
+            input,
+            kind,
+            context: None,
+            rejection: None,
+        }
+    }
+
+    fn append(_input: &'a [u8], _kind: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> ContextError<&'a [u8]> for NomError<'a> {
+    fn add_context(_input: &'a [u8], context: &'static str, mut other: Self) -> Self {
+        other.context.get_or_insert(context);
+        other
+    }
+}
+
+impl From<ParseError> for nom::Err<NomError<'_>> {
+    fn from(err: ParseError) -> Self {
+        nom::Err::Failure(NomError {
+            input: &[],
+            kind: ErrorKind::Verify,
+            context: Some(err.context()),
+            rejection: Some(Box::new(err)),
+        })
+    }
+}
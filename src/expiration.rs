@@ -0,0 +1,133 @@
+//! Checking if a document has expired.
+
+use jiff::{Zoned, tz::TimeZone};
+use serde::Serialize;
+
+use crate::{
+    DecodedData,
+    clock::Clock,
+    data::{Expiration, IssuerIdentification},
+};
+
+/// If a document had expired at some moment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpirationStatus {
+    Valid,
+    Expired,
+    NonExpiring,
+    /// The document's expiration date was missing or could not be parsed.
+    Unknown,
+}
+
+impl DecodedData {
+    /// The moment the document expires, which is the end of its expiration
+    /// day in the issuing jurisdiction.
+    ///
+    /// The westernmost time zone of the jurisdiction is used, so a document
+    /// is valid until its expiration day has ended everywhere the issuer
+    /// covers. Documents from unknown issuers use `fallback`.
+    pub fn expires_at(&self, fallback: &TimeZone) -> Option<Zoned> {
+        let Expiration::On(date) = self.expiration else {
+            return None;
+        };
+
+        let time_zone = IssuerIdentification::try_from(self.issuer_id)
+            .ok()
+            .and_then(|issuer| jiff::tz::db().get(issuer.time_zone()).ok())
+            .unwrap_or_else(|| fallback.clone());
+
+        date.tomorrow().ok()?.to_zoned(time_zone).ok()
+    }
+
+    /// If the document had expired at a moment.
+    ///
+    /// Documents from unknown issuers are judged in the time zone of `at`.
+    pub fn expiration_status(&self, at: &Zoned) -> ExpirationStatus {
+        match self.expiration {
+            Expiration::NonExpiring => ExpirationStatus::NonExpiring,
+            Expiration::Unknown => ExpirationStatus::Unknown,
+            Expiration::On(_) => match self.expires_at(at.time_zone()) {
+                Some(expires_at) if *at >= expires_at => ExpirationStatus::Expired,
+                Some(_) => ExpirationStatus::Valid,
+                None => ExpirationStatus::Unknown,
+            },
+        }
+    }
+
+    /// If the document has expired now.
+    pub fn expiration_status_now(&self, clock: &impl Clock) -> ExpirationStatus {
+        self.expiration_status(&clock.now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::LICENSE_FOLDER;
+    use crate::{BarcodeBuilder, ParseOptions, SubfileType};
+
+    #[test]
+    fn test_expiration() {
+        let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/VA-21.txt")).unwrap();
+        let decoded = DecodedData::from(crate::parse_barcode_bytes(&input).unwrap());
+        assert_eq!(
+            decoded.expiration,
+            Expiration::On(jiff::civil::date(2031, 3, 23))
+        );
+
+        let cases = [
+            (
+                "2031-03-23T23:59:59-04:00[America/New_York]",
+                ExpirationStatus::Valid,
+            ),
+            (
+                "2031-03-24T00:00:00-04:00[America/New_York]",
+                ExpirationStatus::Expired,
+            ),
+            // Still the 23rd in Virginia.
+            ("2031-03-24T03:30:00+00:00[UTC]", ExpirationStatus::Valid),
+            ("2031-03-24T04:00:00+00:00[UTC]", ExpirationStatus::Expired),
+        ];
+
+        for (at, expected) in cases {
+            let at: Zoned = at.parse().unwrap();
+            assert_eq!(decoded.expiration_status(&at), expected, "{at}");
+        }
+
+        let cases = [
+            ("99999999", Expiration::NonExpiring),
+            ("00000000", Expiration::NonExpiring),
+            ("12319999", Expiration::NonExpiring),
+            ("13452031", Expiration::Unknown),
+            ("0", Expiration::Unknown),
+            ("9", Expiration::Unknown),
+            ("999999999", Expiration::Unknown),
+            ("07042031", Expiration::On(jiff::civil::date(2031, 7, 4))),
+        ];
+
+        for (value, expected) in cases {
+            let payload = BarcodeBuilder::new(636014, 10)
+                .element(SubfileType::DL, "DBA", value)
+                .build()
+                .unwrap();
+            let report = crate::parse_barcode_with(&payload, &ParseOptions::default()).unwrap();
+            assert_eq!(report.data.expiration(), expected, "{value}");
+            assert_eq!(
+                report.diagnostics.is_empty(),
+                expected != Expiration::Unknown,
+                "{value}"
+            );
+
+            let decoded = DecodedData::from(report.data);
+            let at: Zoned = "2031-07-05T06:30:00+00:00[UTC]".parse().unwrap();
+            let expected_status = match expected {
+                // Still the 4th in California.
+                Expiration::On(_) => ExpirationStatus::Valid,
+                Expiration::NonExpiring => ExpirationStatus::NonExpiring,
+                Expiration::Unknown => ExpirationStatus::Unknown,
+            };
+            assert_eq!(decoded.expiration_status(&at), expected_status, "{value}");
+        }
+    }
+}
@@ -1,54 +1,179 @@
-use std::{collections::HashMap, str::FromStr};
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
 
 use nom::{
     IResult, Parser,
     branch::alt,
     bytes::complete::{tag, take, take_till, take_until},
-    character::complete::{alpha1, digit1, multispace0},
-    combinator::{eof, map_parser, map_res, opt},
+    character::complete::{alpha1, digit1},
+    combinator::{eof, map_opt, map_parser, opt},
     error::context,
     multi::many0,
 };
 use once_cell::sync::Lazy;
 use regex_lite::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use tap::TapFallible;
 
 pub use data::DecodedData;
-use data::IssuerIdentification;
-
+use data::{DateFormat, IssuerIdentification};
+pub use elements::ElementId;
+pub use encode::{BarcodeBuilder, EncodeError};
+use error::NomError;
+pub use error::ParseError;
+pub use options::{ParseOptions, Strictness, TextEncoding};
+pub use raw::{RawData, RawElement, RawSegment, RawSubfile, Span};
+use report::Diagnostics;
+pub use report::{Diagnostic, ParseReport};
+
+pub mod address;
+pub mod age;
+pub mod clock;
 pub mod data;
-
-#[derive(Debug, Serialize)]
+pub mod elements;
+mod encode;
+mod error;
+pub mod expiration;
+mod options;
+pub mod policy;
+mod raw;
+mod report;
+
+/// Data parsed from a barcode, borrowing from the input where possible.
+///
+/// Use [`Data::into_owned`] to get an [`OwnedData`] that can be stored or
+/// sent across threads.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "web", derive(tsify::Tsify))]
 #[cfg_attr(feature = "web", tsify(into_wasm_abi))]
 pub struct Data<'a> {
     pub header: Header,
-    pub subfiles: HashMap<SubfileType, HashMap<&'a str, Option<&'a str>>>,
+    pub subfiles: HashMap<SubfileType, HashMap<Cow<'a, str>, Option<Cow<'a, str>>>>,
+    /// Everything that was read from the barcode, without any cleanup.
+    pub raw: RawData<'a>,
+    /// The raw bytes the barcode was parsed from, which the spans in `raw`
+    /// point into.
+    pub input: Cow<'a, [u8]>,
+    /// The date format chosen in [`ParseOptions`], used by every date
+    /// accessor instead of the issuer's. It is serialized so deserialized
+    /// data reads dates the same way.
+    #[serde(default)]
+    date_format_override: Option<DateFormat>,
+}
+
+/// Data that does not borrow from the input, which can be deserialized and
+/// used anywhere a [`Data`] can.
+pub type OwnedData = Data<'static>;
+
+impl<'a> Data<'a> {
+    /// Copy everything borrowed from the input so the data can outlive it.
+    pub fn into_owned(self) -> OwnedData {
+        Data {
+            header: self.header,
+            subfiles: self
+                .subfiles
+                .into_iter()
+                .map(|(subfile_type, elements)| {
+                    let elements = elements
+                        .into_iter()
+                        .map(|(id, value)| {
+                            (
+                                Cow::Owned(id.into_owned()),
+                                value.map(|value| Cow::Owned(value.into_owned())),
+                            )
+                        })
+                        .collect();
+
+                    (subfile_type, elements)
+                })
+                .collect(),
+            raw: self.raw.into_owned(),
+            input: Cow::Owned(self.input.into_owned()),
+            date_format_override: self.date_format_override,
+        }
+    }
+
+    /// The raw element that a value in [`Data::subfiles`] was taken from,
+    /// including where it was read from in the input.
+    ///
+    /// If a subfile or element appeared more than once the last one is used,
+    /// as that is the one whose value is kept in the map.
+    pub fn raw_element(&self, subfile_type: SubfileType, id: &str) -> Option<&RawElement<'a>> {
+        self.raw
+            .subfiles
+            .iter()
+            .rev()
+            .find(|subfile| subfile.designator.subfile_type == subfile_type)?
+            .elements
+            .iter()
+            .rev()
+            .find(|elem| elem.id == id)
+    }
 }
 
-#[derive(Debug, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "web", derive(tsify::Tsify))]
 pub struct Header {
+    pub separators: Separators,
     pub issuer_id: u32,
     pub version_number: u8,
     pub jurisdiction_version_number: Option<u8>,
     pub number_of_entries: u8,
     pub subfile_designators: Vec<SubfileDesignator>,
+    /// Where each field of the header was read from.
+    pub spans: HeaderSpans,
+}
+
+/// Byte spans of the fields in a [`Header`], measured against the original
+/// input.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct HeaderSpans {
+    pub compliance_indicator: Span,
+    pub separators: Span,
+    pub file_type: Span,
+    pub issuer_id: Span,
+    pub version_number: Span,
+    pub jurisdiction_version_number: Option<Span>,
+    pub number_of_entries: Span,
+}
+
+/// Characters declared at the start of the header that separate the parts of
+/// the barcode.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct Separators {
+    /// Ends each data element, normally a line feed.
+    pub data_element: u8,
+    /// Separates records, normally `0x1E`.
+    pub record: u8,
+    /// Ends each subfile, normally a carriage return.
+    pub segment_terminator: u8,
+}
+
+impl Default for Separators {
+    fn default() -> Self {
+        Self {
+            data_element: b'\n',
+            record: 0x1e,
+            segment_terminator: b'\r',
+        }
+    }
+}
+
+impl Separators {
+    fn is_terminator(&self, b: u8) -> bool {
+        b == self.data_element || b == self.segment_terminator
+    }
 }
 
-#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[cfg_attr(feature = "web", derive(tsify::Tsify))]
 pub struct SubfileDesignator {
     pub subfile_type: SubfileType,
     pub offset: u32,
     pub length: u32,
-}
-
-#[derive(Debug, PartialEq, Eq)]
-struct DataElement<'a> {
-    id: &'a str,
-    value: Option<&'a str>,
+    /// Where the designator was read from in the header.
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -58,6 +183,7 @@ pub enum SubfileType {
     EN,
     ID,
     JurisdictionSpecific(char),
+    Unknown(char, char),
 }
 
 impl std::fmt::Display for SubfileType {
@@ -67,6 +193,7 @@ impl std::fmt::Display for SubfileType {
             Self::EN => write!(f, "EN"),
             Self::ID => write!(f, "ID"),
             Self::JurisdictionSpecific(c) => write!(f, "Z{c}"),
+            Self::Unknown(a, b) => write!(f, "{a}{b}"),
         }
     }
 }
@@ -83,6 +210,17 @@ impl Serialize for SubfileType {
     }
 }
 
+impl<'de> Deserialize<'de> for SubfileType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 #[derive(Debug)]
 pub struct UnknownSubfileType {
     pub data: String,
@@ -110,83 +248,189 @@ impl FromStr for SubfileType {
                 })?;
                 Ok(Self::JurisdictionSpecific(c))
             }
-            _ => Err(UnknownSubfileType {
-                data: s.to_string(),
-            }),
+            _ => match *s.as_bytes() {
+                [a, b] if a.is_ascii_uppercase() && b.is_ascii_uppercase() => {
+                    Ok(Self::Unknown(char::from(a), char::from(b)))
+                }
+                _ => Err(UnknownSubfileType {
+                    data: s.to_string(),
+                }),
+            },
         }
     }
 }
 
-fn parse_header(input: &str) -> IResult<&str, (&str, Header)> {
-    let (start, _) = take_until("@")(input)?;
-    let (input, _) = context("compliance indicator", tag("@")).parse(start)?;
+type ParseResult<'a, O> = IResult<&'a [u8], O, NomError<'a>>;
+
+/// The length of a subfile designator: a two letter subfile type followed by
+/// a four digit offset and a four digit length.
+const SUBFILE_DESIGNATOR_LENGTH: usize = 10;
+
+fn parse_header<'a>(
+    input: &'a [u8],
+    diagnostics: &mut Diagnostics,
+) -> ParseResult<'a, (&'a [u8], Header)> {
+    let original = input;
 
-    let (input, _) = multispace0(input)?;
-    let (input, _) = context("record separator", take_until("A")).parse(input)?;
-    let (input, _) = context("file type", alt((tag("ANSI "), tag("AAMVA")))).parse(input)?;
+    let (start, _) = context("compliance indicator", take_until("@")).parse(input)?;
+    let (input, indicator) = context("compliance indicator", tag("@")).parse(start)?;
 
+    let (input, declared) = context("record separator", take_until("A")).parse(input)?;
+
+    let separators = match *declared {
+        [data_element, record, segment_terminator] => Separators {
+            data_element,
+            record,
+            segment_terminator,
+        },
+        _ => {
+            tracing::warn!(
+                len = declared.len(),
+                "header did not declare three separators, using defaults"
+            );
+            diagnostics.report(
+                declared,
+                "record separator",
+                Diagnostic::InvalidSeparators {
+                    declared: declared.to_vec(),
+                },
+            )?;
+            Separators::default()
+        }
+    };
+    let (input, file_type) =
+        context("file type", alt((tag("ANSI "), tag("AAMVA")))).parse(input)?;
+
+    let issuer_input = input;
     let (input, issuer_id) = context(
         "issuer identification number",
-        map_res(map_parser(take(6usize), digit1), |s: &str| s.parse::<u32>()),
+        map_opt(map_parser(take(6usize), digit1), parse_ascii::<u32>),
     )
     .parse(input)?;
 
+    let mut spans = HeaderSpans {
+        compliance_indicator: Span::of(original, indicator),
+        separators: Span::of(original, declared),
+        file_type: Span::of(original, file_type),
+        issuer_id: Span::between(original, issuer_input, input),
+        ..Default::default()
+    };
+
     let issuer = IssuerIdentification::try_from(issuer_id)
-        .tap_err(|err| tracing::warn!("could not decode issuer identification number: {err}"))
+        .tap_err(|err| {
+            tracing::warn!("could not decode issuer identification number: {err}");
+            diagnostics.note(Diagnostic::UnknownIssuer { issuer_id });
+        })
         .ok();
 
+    let version_input = input;
     let (input, version_number) = context("aamva version number", digit_0_to_99).parse(input)?;
+    spans.version_number = Span::between(original, version_input, input);
 
     let (input, jurisdiction_version_number) = if version_number > 2 {
+        let jurisdiction_version_input = input;
         let (input, jurisdiction_version_number) =
             context("jurisdiction version number", digit_0_to_99).parse(input)?;
+        spans.jurisdiction_version_number =
+            Some(Span::between(original, jurisdiction_version_input, input));
         (input, Some(jurisdiction_version_number))
     } else {
         (input, None)
     };
 
+    let entries = input;
     let (input, number_of_entries) = context("number of entries", digit_0_to_99).parse(input)?;
+    spans.number_of_entries = Span::between(original, entries, input);
+
     let (input, subfile_designators) = context(
         "subfile designators",
-        many0(|s| parse_subfile_designator(s, start, issuer, version_number)),
+        many0(|s| {
+            parse_subfile_designator(s, original, start, issuer, version_number, diagnostics)
+        }),
     )
     .parse(input)?;
 
+    // If the input ends before we saw every designator the header says should
+    // exist, the scanner most likely cut the read short.
+    if subfile_designators.len() < number_of_entries as usize
+        && input.len() < SUBFILE_DESIGNATOR_LENGTH
+    {
+        if diagnostics.is_strict() {
+            return Err(nom::Err::Failure(NomError {
+                input,
+                kind: nom::error::ErrorKind::Eof,
+                context: Some("subfile designators"),
+                rejection: None,
+            }));
+        }
+
+        tracing::warn!(
+            declared = number_of_entries,
+            found = subfile_designators.len(),
+            "input ended before every subfile designator"
+        );
+        diagnostics.note(Diagnostic::TruncatedSubfileDesignators {
+            declared: number_of_entries,
+            found: subfile_designators.len(),
+        });
+    } else if subfile_designators.len() != number_of_entries as usize {
+        diagnostics.report(
+            entries,
+            "number of entries",
+            Diagnostic::EntryCountMismatch {
+                declared: number_of_entries,
+                found: subfile_designators.len(),
+            },
+        )?;
+    }
+
     Ok((
         input,
         (
             start,
             Header {
+                separators,
                 issuer_id,
                 version_number,
                 jurisdiction_version_number,
                 number_of_entries,
                 subfile_designators,
+                spans,
             },
         ),
     ))
 }
 
 fn parse_subfile_designator<'a>(
-    input: &'a str,
-    start: &str,
+    input: &'a [u8],
+    original: &[u8],
+    start: &[u8],
     issuer: Option<IssuerIdentification>,
     version: u8,
-) -> IResult<&'a str, SubfileDesignator> {
+    diagnostics: &mut Diagnostics,
+) -> ParseResult<'a, SubfileDesignator> {
     static MATCHER: Lazy<Regex> = Lazy::new(|| {
         Regex::new(r"(DL|ID)([\d\w]{3,8})(DL|ID|Z\w)([DZ][A-Z]{2})").expect("regex should compile")
     });
 
+    let designator_input = input;
     let (input, subfile_type) = context(
         "subfile type",
-        map_res(take(2usize), |s: &str| s.parse::<SubfileType>()),
+        map_opt(take(2usize), parse_ascii::<SubfileType>),
     )
     .parse(input)?;
 
     let guess_offset = || {
         let mut offset = 0;
 
-        if let Some(m) = MATCHER.find(start) {
+        // Replace any non-ASCII bytes so offsets in the string still match
+        // offsets in the input.
+        let start: String = start
+            .iter()
+            .map(|&b| if b.is_ascii() { b as char } else { '?' })
+            .collect();
+
+        if let Some(m) = MATCHER.find(&start) {
             offset = m.end() as u32 - 5;
         }
         tracing::warn!(
@@ -196,24 +440,46 @@ fn parse_subfile_designator<'a>(
         offset
     };
 
+    let offset_input = input;
+
     let (input, offset, length) =
-        if let Ok((input, _garbage)) = tag::<_, _, nom::error::Error<&str>>("abac")(input) {
+        if let Ok((input, _garbage)) = tag::<_, _, NomError>("abac")(input) {
             let offset = guess_offset();
+            diagnostics.report(
+                offset_input,
+                "subfile offset",
+                Diagnostic::GuessedSubfileOffset {
+                    subfile_type,
+                    offset,
+                },
+            )?;
             (input, offset, start.len() as u32)
         } else {
             let (input, mut offset) = context("subfile offset", digit_4char).parse(input)?;
+            let (input, length) = context("subfile length", digit_4char).parse(input)?;
 
             if version == 1 && issuer == Some(IssuerIdentification::SouthCarolina) && offset == 30 {
                 tracing::debug!("applying fix for south carolina offset");
+                diagnostics.report(
+                    offset_input,
+                    "subfile offset",
+                    Diagnostic::SouthCarolinaOffsetFix { subfile_type },
+                )?;
                 offset -= 1;
             }
 
             if offset == 0 {
                 offset = guess_offset();
+                diagnostics.report(
+                    offset_input,
+                    "subfile offset",
+                    Diagnostic::GuessedSubfileOffset {
+                        subfile_type,
+                        offset,
+                    },
+                )?;
             }
 
-            let (input, length) = context("subfile length", digit_4char).parse(input)?;
-
             (input, offset, length)
         };
 
@@ -223,14 +489,19 @@ fn parse_subfile_designator<'a>(
             subfile_type,
             offset,
             length,
+            span: Span::between(original, designator_input, input),
         },
     ))
 }
 
-fn parse_data_elements(
-    input: &str,
-    subfile: SubfileDesignator,
-) -> IResult<&str, HashMap<&str, Option<&str>>> {
+fn parse_data_elements<'a>(
+    original: &'a [u8],
+    input: &'a [u8],
+    subfile: &SubfileDesignator,
+    separators: &Separators,
+    encoding: TextEncoding,
+    diagnostics: &mut Diagnostics,
+) -> ParseResult<'a, RawSubfile<'a>> {
     let (input, _offset) = take(subfile.offset as usize)(input)?;
 
     let max_length = std::cmp::min(subfile.length as usize, input.len());
@@ -243,82 +514,307 @@ fn parse_data_elements(
             clamped_length = max_length,
             "subfile had offset+length that exceeded input length"
         );
+        diagnostics.report(
+            input,
+            "subfile length",
+            Diagnostic::ClampedSubfileLength {
+                subfile_type: subfile.subfile_type,
+                length: subfile.length,
+                clamped_length: max_length as u32,
+            },
+        )?;
     }
 
     let (_input, element_data) = take(max_length)(input)?;
+    let span = Span::of(original, element_data);
 
-    let element_data = if matches!(
+    let is_standard_subfile = matches!(
         subfile.subfile_type,
         SubfileType::DL | SubfileType::EN | SubfileType::ID
-    ) {
-        let (element_data, _) =
+    );
+
+    let element_data = if is_standard_subfile {
+        let (element_data, marker) =
             opt(tag(subfile.subfile_type.to_string().as_bytes())).parse(element_data)?;
+
+        if marker.is_none() {
+            diagnostics.report(
+                element_data,
+                "subfile type marker",
+                Diagnostic::MissingSubfileTypeMarker {
+                    subfile_type: subfile.subfile_type,
+                },
+            )?;
+        }
+
         element_data
     } else {
         element_data
     };
 
-    let (input, elements) =
-        many0(|input| parse_data_element(input, subfile.subfile_type)).parse(element_data)?;
-
-    let elements = elements
-        .into_iter()
-        .map(|elem| (elem.id, elem.value.map(str::trim)))
-        .collect();
-
-    Ok((input, elements))
-}
+    let (trailing, elements) =
+        many0(|input| parse_data_element(original, input, separators)).parse(element_data)?;
 
-fn parse_data_element(input: &str, subfile_type: SubfileType) -> IResult<&str, DataElement<'_>> {
-    let prefix = match subfile_type {
-        SubfileType::DL | SubfileType::EN | SubfileType::ID => "D".to_string(),
-        SubfileType::JurisdictionSpecific(c) => format!("Z{c}"),
+    let prefix = match subfile.subfile_type {
+        SubfileType::DL | SubfileType::EN | SubfileType::ID => Some("D".to_string()),
+        SubfileType::JurisdictionSpecific(c) => Some(format!("Z{c}")),
+        SubfileType::Unknown(..) => None,
     };
 
-    // Get the 3-letter ID for this element.
-    let (input, id) = map_parser(take(3usize), alpha1).parse(input)?;
+    for elem in &elements {
+        if let Some(prefix) = &prefix
+            && !elem.id.starts_with(prefix.as_str())
+        {
+            tracing::warn!(
+                "element in subfile {} had wrong ID prefix: {}",
+                subfile.subfile_type,
+                elem.id
+            );
+            diagnostics.report(
+                elem.id.as_bytes(),
+                "data element",
+                Diagnostic::WrongElementPrefix {
+                    subfile_type: subfile.subfile_type,
+                    id: elem.id.to_string(),
+                },
+            )?;
+        }
 
-    if !id.starts_with(&prefix) {
-        tracing::warn!("element in subfile {subfile_type} had wrong ID prefix: {id}");
+        if is_standard_subfile
+            && data::DATE_ELEMENTS.contains(&elem.id.as_ref())
+            && let Some(value) = normalize_value(&elem.value, encoding)
+            && !data::is_date_shaped(&value)
+        {
+            diagnostics.report(
+                elem.id.as_bytes(),
+                "date",
+                Diagnostic::MalformedDate {
+                    id: elem.id.to_string(),
+                    value: value.to_string(),
+                },
+            )?;
+        }
     }
 
+    Ok((
+        trailing,
+        RawSubfile {
+            designator: subfile.clone(),
+            span,
+            elements,
+            trailing: Cow::Borrowed(trailing),
+        },
+    ))
+}
+
+fn parse_data_element<'a>(
+    original: &'a [u8],
+    input: &'a [u8],
+    separators: &Separators,
+) -> ParseResult<'a, RawElement<'a>> {
+    // Get the 3-letter ID for this element.
+    let (input, id) = map_opt(map_parser(take(3usize), alpha1), |id| {
+        std::str::from_utf8(id).ok()
+    })
+    .parse(input)?;
+
     // Take values until we reach a terminator, then take the terminator.
-    let (input, value) = take_till(|s| matches!(s, '\r' | '\n'))(input)?;
+    let (input, value) = take_till(|b| separators.is_terminator(b))(input)?;
     let (input, _) = alt((take(1usize), eof)).parse(input)?;
 
-    let value = match value.trim() {
+    Ok((
+        input,
+        RawElement {
+            id: Cow::Borrowed(id),
+            value: Cow::Borrowed(value),
+            id_span: Span::of(original, id.as_bytes()),
+            value_span: Span::of(original, value),
+        },
+    ))
+}
+
+/// Decode and trim a raw element value, treating placeholders for missing
+/// data as no value at all.
+///
+/// Values borrowed from the input stay borrowed where possible.
+fn normalize_value<'a>(value: &Cow<'a, [u8]>, encoding: TextEncoding) -> Option<Cow<'a, str>> {
+    let value = match value {
+        Cow::Borrowed(value) => encoding.decode(value),
+        Cow::Owned(value) => Cow::Owned(encoding.decode(value).into_owned()),
+    };
+
+    let value = match value {
+        Cow::Borrowed(value) => Cow::Borrowed(value.trim()),
+        Cow::Owned(value) => Cow::Owned(value.trim().to_string()),
+    };
+
+    match value.as_ref() {
         "NONE" | "unavl" | "" => None,
-        value => Some(value),
+        _ => Some(value),
+    }
+}
+
+fn parse_subfile<'a>(
+    input: &'a [u8],
+    start: &'a [u8],
+    designator: &SubfileDesignator,
+    separators: &Separators,
+    encoding: TextEncoding,
+    diagnostics: &mut Diagnostics,
+) -> Result<RawSubfile<'a>, ParseError> {
+    if designator.offset as usize > start.len() {
+        return Err(ParseError::SubfileOutOfBounds {
+            subfile_type: designator.subfile_type,
+            offset: input.len() - start.len() + designator.offset as usize,
+            context: "subfile offset",
+        });
+    }
+
+    parse_data_elements(input, start, designator, separators, encoding, diagnostics)
+        .map(|(_input, subfile)| subfile)
+        .map_err(|err| ParseError::from_nom(input, err))
+}
+
+fn parse<'a>(
+    input: &'a [u8],
+    options: &ParseOptions,
+    diagnostics: &mut Diagnostics,
+) -> Result<Data<'a>, ParseError> {
+    let encoding = options.encoding;
+
+    let (trailing, (start, header)) =
+        parse_header(input, diagnostics).map_err(|err| ParseError::from_nom(input, err))?;
+
+    let start_offset = input.len() - start.len();
+    let mut covered = Vec::with_capacity(header.subfile_designators.len() + 1);
+    covered.push(start_offset..input.len() - trailing.len());
+
+    let mut subfiles = HashMap::new();
+    let mut raw_subfiles = Vec::new();
+
+    for designator in &header.subfile_designators {
+        let subfile_type = designator.subfile_type;
+
+        match parse_subfile(
+            input,
+            start,
+            designator,
+            &header.separators,
+            encoding,
+            diagnostics,
+        ) {
+            Ok(raw_subfile) => {
+                covered.push(raw_subfile.span.range());
+
+                let elements = raw_subfile
+                    .elements
+                    .iter()
+                    .map(|elem| (elem.id.clone(), normalize_value(&elem.value, encoding)))
+                    .collect();
+
+                subfiles.insert(subfile_type, elements);
+                raw_subfiles.push(raw_subfile);
+            }
+            Err(error) if diagnostics.is_strict() => return Err(error),
+            Err(error) => {
+                tracing::warn!(%subfile_type, "subfile could not be parsed: {error}");
+                diagnostics.note(Diagnostic::DroppedSubfile {
+                    subfile_type,
+                    error,
+                });
+            }
+        }
+    }
+
+    Ok(Data {
+        header,
+        subfiles,
+        raw: RawData {
+            subfiles: raw_subfiles,
+            leftover: raw::leftover_segments(input, covered),
+            encoding,
+        },
+        input: Cow::Borrowed(input),
+        date_format_override: options.date_format,
+    })
+}
+
+/// Parse a barcode that was already decoded into text.
+///
+/// Use [`parse_barcode_bytes`] for the bytes read by a scanner.
+pub fn parse_barcode(input: &str) -> Result<Data<'_>, ParseError> {
+    let input = input.as_bytes();
+
+    let options = ParseOptions {
+        encoding: TextEncoding::Utf8,
+        ..Default::default()
+    };
+
+    parse(
+        input,
+        &options,
+        &mut Diagnostics::new(input, options.strictness),
+    )
+}
+
+/// Parse a barcode from the raw bytes read by a scanner.
+///
+/// Element values are ASCII or ISO-8859-1, the character sets the standard
+/// allows, and are decoded into Unicode. ASCII values are borrowed from the
+/// input.
+pub fn parse_barcode_bytes(input: &[u8]) -> Result<Data<'_>, ParseError> {
+    let options = ParseOptions {
+        encoding: TextEncoding::Latin1,
+        ..Default::default()
     };
 
-    Ok((input, DataElement { id, value }))
+    parse(
+        input,
+        &options,
+        &mut Diagnostics::new(input, options.strictness),
+    )
 }
 
-pub fn parse_barcode(input: &str) -> Result<Data<'_>, nom::Err<nom::error::Error<&str>>> {
-    let (_trailing, (start, header)) = parse_header(input)?;
-
-    let subfiles = header
-        .subfile_designators
-        .clone()
-        .into_iter()
-        .flat_map(|desginator| {
-            let subfile_type = desginator.subfile_type;
-            parse_data_elements(start, desginator)
-                .map(|(_input, elements)| (subfile_type, elements))
-                .tap_err(|err| tracing::warn!(%subfile_type, "subfile could not be parsed: {err}"))
-                .ok()
-        })
-        .collect::<HashMap<_, _>>();
+/// Parse a barcode that was already decoded into text, collecting every
+/// workaround applied and problem skipped over along the way.
+pub fn parse_barcode_with_report(input: &str) -> Result<ParseReport<'_>, ParseError> {
+    parse_barcode_with(
+        input.as_bytes(),
+        &ParseOptions {
+            encoding: TextEncoding::Utf8,
+            ..Default::default()
+        },
+    )
+}
 
-    Ok(Data { header, subfiles })
+/// Parse a barcode from raw bytes with the given options, collecting every
+/// workaround applied and problem skipped over along the way.
+///
+/// When parsing strictly, anything the lenient parser would have worked
+/// around is returned as an error instead.
+pub fn parse_barcode_with<'a>(
+    input: &'a [u8],
+    options: &ParseOptions,
+) -> Result<ParseReport<'a>, ParseError> {
+    let mut diagnostics = Diagnostics::new(input, options.strictness);
+    let data = parse(input, options, &mut diagnostics)?;
+
+    let mut diagnostics = diagnostics.diagnostics;
+    diagnostics.extend(data.date_diagnostics());
+
+    Ok(ParseReport { data, diagnostics })
 }
 
-fn digit_0_to_99(input: &str) -> IResult<&str, u8> {
-    map_res(map_parser(take(2usize), digit1), |s: &str| s.parse::<u8>()).parse(input)
+fn parse_ascii<T: FromStr>(input: &[u8]) -> Option<T> {
+    std::str::from_utf8(input).ok()?.parse().ok()
 }
 
-fn digit_4char(input: &str) -> IResult<&str, u32> {
-    map_res(map_parser(take(4usize), digit1), |s: &str| s.parse::<u32>()).parse(input)
+fn digit_0_to_99(input: &[u8]) -> ParseResult<'_, u8> {
+    map_opt(map_parser(take(2usize), digit1), parse_ascii::<u8>).parse(input)
+}
+
+fn digit_4char(input: &[u8]) -> ParseResult<'_, u32> {
+    map_opt(map_parser(take(4usize), digit1), parse_ascii::<u32>).parse(input)
 }
 
 #[cfg(test)]
@@ -329,9 +825,9 @@ mod tests {
 
     use super::*;
 
-    static LICENSE_FOLDER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/licenses");
+    pub(crate) static LICENSE_FOLDER: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/licenses");
 
-    fn get_test_files() -> impl Iterator<Item = walkdir::DirEntry> {
+    pub(crate) fn get_test_files() -> impl Iterator<Item = walkdir::DirEntry> {
         let path = if let Some(path) = std::env::var_os("AAMVA_TEST_FOLDER") {
             PathBuf::from(path)
         } else {
@@ -362,34 +858,57 @@ mod tests {
         });
     }
 
+    /// Build a barcode with the given `DL` subfile elements and parse it.
+    pub(crate) fn parse_elements(
+        issuer_id: u32,
+        version: u8,
+        elements: &[(&str, &str)],
+    ) -> OwnedData {
+        let payload = elements
+            .iter()
+            .fold(
+                BarcodeBuilder::new(issuer_id, version),
+                |builder, (id, value)| builder.element(SubfileType::DL, *id, *value),
+            )
+            .build()
+            .unwrap();
+
+        parse_barcode_bytes(&payload).unwrap().into_owned()
+    }
+
     #[test]
     fn test_parse_data_element() {
         let cases = [
             (
-                (SubfileType::DL, "DAQ0123456789ABC\rtest"),
+                "DAQ0123456789ABC\rtest",
                 (
                     "test",
-                    DataElement {
-                        id: "DAQ",
-                        value: Some("0123456789ABC"),
+                    RawElement {
+                        id: Cow::Borrowed("DAQ"),
+                        value: Cow::Borrowed(b"0123456789ABC"),
+                        id_span: Span { start: 0, end: 3 },
+                        value_span: Span { start: 3, end: 16 },
                     },
                 ),
             ),
             (
-                (SubfileType::DL, "DAQ0123456789ABC"),
+                "DAQ0123456789ABC",
                 (
                     "",
-                    DataElement {
-                        id: "DAQ",
-                        value: Some("0123456789ABC"),
+                    RawElement {
+                        id: Cow::Borrowed("DAQ"),
+                        value: Cow::Borrowed(b"0123456789ABC"),
+                        id_span: Span { start: 0, end: 3 },
+                        value_span: Span { start: 3, end: 16 },
                     },
                 ),
             ),
         ];
 
-        for ((prefix, input), expected_output) in cases {
-            let actual_output = parse_data_element(input, prefix).unwrap();
-            assert_eq!(actual_output, expected_output);
+        for (input, (expected_input, expected_element)) in cases {
+            let input = input.as_bytes();
+            let actual_output = parse_data_element(input, input, &Separators::default()).unwrap();
+            assert_eq!(actual_output, (expected_input.as_bytes(), expected_element));
         }
     }
 
@@ -402,6 +921,7 @@ mod tests {
                 (
                     "@\n\x1e\rAAMVA6360000102DL00390188ZV02270031ANSI ",
                     Header {
+                        separators: Separators::default(),
                         issuer_id: 636000,
                         version_number: 1,
                         number_of_entries: 2,
@@ -411,21 +931,787 @@ mod tests {
                                 subfile_type: SubfileType::DL,
                                 offset: 39,
                                 length: 188,
+                                span: Span { start: 19, end: 29 },
                             },
                             SubfileDesignator {
                                 subfile_type: SubfileType::JurisdictionSpecific('V'),
                                 offset: 227,
                                 length: 31,
+                                span: Span { start: 29, end: 39 },
                             },
                         ],
+                        spans: HeaderSpans {
+                            compliance_indicator: Span { start: 0, end: 1 },
+                            separators: Span { start: 1, end: 4 },
+                            file_type: Span { start: 4, end: 9 },
+                            issuer_id: Span { start: 9, end: 15 },
+                            version_number: Span { start: 15, end: 17 },
+                            jurisdiction_version_number: None,
+                            number_of_entries: Span { start: 17, end: 19 },
+                        },
                     },
                 ),
             ),
         )];
 
-        for (input, expected_output) in cases {
-            let actual_output = parse_header(input).unwrap();
-            assert_eq!(actual_output, expected_output);
+        for (input, (expected_input, (expected_start, expected_header))) in cases {
+            let input = input.as_bytes();
+            let actual_output =
+                parse_header(input, &mut Diagnostics::new(input, Strictness::Lenient)).unwrap();
+            assert_eq!(
+                actual_output,
+                (
+                    expected_input.as_bytes(),
+                    (expected_start.as_bytes(), expected_header)
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let cases = [
+            (
+                "not a barcode",
+                ParseError::MissingComplianceIndicator {
+                    offset: 0,
+                    context: "compliance indicator",
+                },
+            ),
+            (
+                "@\n\x1e\rANSI X36000",
+                ParseError::InvalidIssuerNumber {
+                    offset: 9,
+                    context: "issuer identification number",
+                },
+            ),
+        ];
+
+        for (input, expected_error) in cases {
+            let actual_error = parse_barcode(input).unwrap_err();
+            assert_eq!(actual_error, expected_error);
+        }
+
+        // Subfiles that cannot be parsed are only errors in strict mode, even
+        // when none of them can be.
+        let input = "@\n\x1e\rAAMVA6360000102DL00900188ZV02270031DLDAQ";
+        let expected_error = ParseError::SubfileOutOfBounds {
+            subfile_type: SubfileType::DL,
+            offset: 90,
+            context: "subfile offset",
+        };
+
+        let report = parse_barcode_with_report(input).unwrap();
+        assert!(report.data.subfiles.is_empty());
+        assert!(report.diagnostics.contains(&Diagnostic::DroppedSubfile {
+            subfile_type: SubfileType::DL,
+            error: expected_error.clone(),
+        }));
+
+        let strict = ParseOptions {
+            strictness: Strictness::Strict,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_barcode_with(input.as_bytes(), &strict).unwrap_err(),
+            expected_error
+        );
+
+        let err = NomError {
+            input: b"0A390188",
+            kind: nom::error::ErrorKind::Digit,
+            context: Some("subfile offset"),
+            rejection: None,
+        };
+        assert_eq!(
+            ParseError::from_nom(b"@\n\x1e\rAAMVA6360000101DL0A390188", nom::Err::Error(err)),
+            ParseError::MalformedSubfileDesignator {
+                offset: 21,
+                context: "subfile offset",
+            }
+        );
+    }
+
+    #[test]
+    fn test_truncated_header() {
+        // The header declares two subfiles but the data of the first one
+        // follows right after its designator.
+        let input = b"@\n\x1e\rANSI 6360000102DL00290008DLDAQ12\r";
+
+        let report = parse_barcode_with(input, &ParseOptions::default()).unwrap();
+        assert_eq!(
+            report.diagnostics,
+            vec![Diagnostic::TruncatedSubfileDesignators {
+                declared: 2,
+                found: 1,
+            }]
+        );
+        assert_eq!(
+            report.data.subfiles[&SubfileType::DL]["DAQ"].as_deref(),
+            Some("12")
+        );
+
+        let input = b"@\n\x1e\rAAMVA6360000102DL00390188ZV02";
+        let report = parse_barcode_with(input, &ParseOptions::default()).unwrap();
+        assert!(report.data.subfiles.is_empty());
+        assert_eq!(
+            report.diagnostics,
+            vec![
+                Diagnostic::TruncatedSubfileDesignators {
+                    declared: 2,
+                    found: 1,
+                },
+                Diagnostic::DroppedSubfile {
+                    subfile_type: SubfileType::DL,
+                    error: ParseError::SubfileOutOfBounds {
+                        subfile_type: SubfileType::DL,
+                        offset: 39,
+                        context: "subfile offset",
+                    },
+                },
+            ]
+        );
+
+        let strict = ParseOptions {
+            strictness: Strictness::Strict,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_barcode_with(input, &strict).unwrap_err(),
+            ParseError::TruncatedSubfileDesignator {
+                offset: 29,
+                context: "subfile designators",
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_report() {
+        let input = "@\n\x1e\rAAMVA6360000102DL00390188ZV09990031DLDAQ123\nXAB1\r";
+
+        let report = parse_barcode_with_report(input).unwrap();
+        assert_eq!(
+            report.diagnostics,
+            vec![
+                Diagnostic::ClampedSubfileLength {
+                    subfile_type: SubfileType::DL,
+                    length: 188,
+                    clamped_length: 14,
+                },
+                Diagnostic::WrongElementPrefix {
+                    subfile_type: SubfileType::DL,
+                    id: "XAB".to_string(),
+                },
+                Diagnostic::DroppedSubfile {
+                    subfile_type: SubfileType::JurisdictionSpecific('V'),
+                    error: ParseError::SubfileOutOfBounds {
+                        subfile_type: SubfileType::JurisdictionSpecific('V'),
+                        offset: 999,
+                        context: "subfile offset",
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_barcode_bytes() {
+        let input = b"@\n\x1e\rANSI 6044280102DL00290017DLDACJOS\xc9\nDCSROY\r";
+
+        let data = parse_barcode_bytes(input).unwrap();
+        let subfile = &data.subfiles[&SubfileType::DL];
+
+        assert_eq!(subfile["DAC"].as_deref(), Some("JOS\u{c9}"));
+        assert!(matches!(subfile["DCS"], Some(Cow::Borrowed("ROY"))));
+        assert_eq!(data.input.as_ref(), input);
+    }
+
+    #[test]
+    fn test_text_encoding() {
+        let input = b"@\n\x1e\rANSI 6044280102DL00290018DLDACJOS\xc3\xa9\nDCSROY\r";
+
+        let data = parse_barcode_bytes(input).unwrap();
+        assert_eq!(
+            data.subfiles[&SubfileType::DL]["DAC"].as_deref(),
+            Some("JOS\u{c3}\u{a9}")
+        );
+        assert_eq!(data.raw.encoding, TextEncoding::Latin1);
+
+        let options = ParseOptions {
+            encoding: TextEncoding::Utf8,
+            ..Default::default()
+        };
+        let data = parse_barcode_with(input, &options).unwrap().data;
+        assert_eq!(
+            data.subfiles[&SubfileType::DL]["DAC"].as_deref(),
+            Some("JOS\u{e9}")
+        );
+        assert_eq!(data.raw.encoding, TextEncoding::Utf8);
+
+        let input = "@\n\x1e\rANSI 6044280102DL00290018DLDACJOS\u{c9}\nDCSROY\r";
+
+        let data = parse_barcode(input).unwrap();
+        assert_eq!(
+            data.subfiles[&SubfileType::DL]["DAC"].as_deref(),
+            Some("JOS\u{c9}")
+        );
+
+        let data = parse_barcode_with_report(input).unwrap().data;
+        assert_eq!(
+            data.subfiles[&SubfileType::DL]["DAC"].as_deref(),
+            Some("JOS\u{c9}")
+        );
+    }
+
+    #[test]
+    fn test_declared_separators() {
+        let input = "@\x1d\x1e\x1cANSI 6360000102DL00290016DLDAQ123\x1dDCSROY\x1c";
+
+        let data = parse_barcode(input).unwrap();
+        let subfile = &data.subfiles[&SubfileType::DL];
+
+        assert_eq!(
+            data.header.separators,
+            Separators {
+                data_element: 0x1d,
+                record: 0x1e,
+                segment_terminator: 0x1c,
+            }
+        );
+        assert_eq!(subfile["DAQ"].as_deref(), Some("123"));
+        assert_eq!(subfile["DCS"].as_deref(), Some("ROY"));
+    }
+
+    #[test]
+    fn test_strict_parsing() {
+        let strict = ParseOptions {
+            strictness: Strictness::Strict,
+            ..Default::default()
+        };
+
+        let cases = [
+            (
+                "@\n\x1e\rANSI 6360000103DL00290016DLDAQ123\nDCSROY\r",
+                ParseError::Nonconforming {
+                    offset: 17,
+                    context: "number of entries",
+                    diagnostic: Box::new(Diagnostic::EntryCountMismatch {
+                        declared: 3,
+                        found: 1,
+                    }),
+                },
+            ),
+            (
+                "@\n\x1e\rANSI 6360000101DL00000016DLDAQ123\nDCSROY\r",
+                ParseError::Nonconforming {
+                    offset: 21,
+                    context: "subfile offset",
+                    diagnostic: Box::new(Diagnostic::GuessedSubfileOffset {
+                        subfile_type: SubfileType::DL,
+                        offset: 29,
+                    }),
+                },
+            ),
+            (
+                "@\n\x1e\rANSI 6360000101DL00290014DAQ123\nDCSROY\r",
+                ParseError::Nonconforming {
+                    offset: 29,
+                    context: "subfile type marker",
+                    diagnostic: Box::new(Diagnostic::MissingSubfileTypeMarker {
+                        subfile_type: SubfileType::DL,
+                    }),
+                },
+            ),
+            (
+                "@\n\x1e\rANSI 6360000101DL00290016DLDBB123\nDCSROY\r",
+                ParseError::Nonconforming {
+                    offset: 31,
+                    context: "date",
+                    diagnostic: Box::new(Diagnostic::MalformedDate {
+                        id: "DBB".to_string(),
+                        value: "123".to_string(),
+                    }),
+                },
+            ),
+        ];
+
+        for (input, expected_error) in cases {
+            assert!(parse_barcode_with(input.as_bytes(), &ParseOptions::default()).is_ok());
+
+            let actual_error = parse_barcode_with(input.as_bytes(), &strict).unwrap_err();
+            assert_eq!(actual_error, expected_error);
+        }
+    }
+
+    #[test]
+    fn test_raw_data() {
+        let input = "@\n\x1e\rANSI 6360000102DL00390019XX00600005DLDAQ1\nDAQ2\nDCS X \r??XXA1\r!!";
+
+        let data = parse_barcode(input).unwrap();
+        let dl = &data.raw.subfiles[0];
+        let unknown = &data.raw.subfiles[1];
+
+        assert_eq!(
+            dl.elements,
+            vec![
+                RawElement {
+                    id: Cow::Borrowed("DAQ"),
+                    value: Cow::Borrowed(b"1"),
+                    id_span: Span { start: 41, end: 44 },
+                    value_span: Span { start: 44, end: 45 },
+                },
+                RawElement {
+                    id: Cow::Borrowed("DAQ"),
+                    value: Cow::Borrowed(b"2"),
+                    id_span: Span { start: 46, end: 49 },
+                    value_span: Span { start: 49, end: 50 },
+                },
+                RawElement {
+                    id: Cow::Borrowed("DCS"),
+                    value: Cow::Borrowed(b" X "),
+                    id_span: Span { start: 51, end: 54 },
+                    value_span: Span { start: 54, end: 57 },
+                },
+            ]
+        );
+        assert_eq!(data.subfiles[&SubfileType::DL]["DAQ"].as_deref(), Some("2"));
+        assert_eq!(data.subfiles[&SubfileType::DL]["DCS"].as_deref(), Some("X"));
+        assert_eq!(dl.designator.span, Span { start: 19, end: 29 });
+        assert_eq!(dl.span, Span { start: 39, end: 58 });
+        assert_eq!(
+            data.raw_element(SubfileType::DL, "DAQ")
+                .map(|elem| elem.value_span),
+            Some(Span { start: 49, end: 50 })
+        );
+        assert_eq!(&input[dl.elements[2].value_span.range()], " X ");
+
+        assert_eq!(
+            unknown.designator.subfile_type,
+            SubfileType::Unknown('X', 'X')
+        );
+        assert_eq!(
+            unknown.elements,
+            vec![RawElement {
+                id: Cow::Borrowed("XXA"),
+                value: Cow::Borrowed(b"1"),
+                id_span: Span { start: 60, end: 63 },
+                value_span: Span { start: 63, end: 64 },
+            }]
+        );
+
+        assert_eq!(
+            data.raw.leftover,
+            vec![
+                RawSegment {
+                    offset: 58,
+                    bytes: Cow::Borrowed(b"??"),
+                },
+                RawSegment {
+                    offset: 65,
+                    bytes: Cow::Borrowed(b"!!"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_field_access() {
+        let input = "@\n\x1e\rANSI 6360000102DL00390020ZV00590006DLDAQ1\nDCSNONE\nDAQ2\rZVA01\r";
+
+        let data = parse_barcode(input).unwrap();
+
+        assert_eq!(
+            data.get(ElementId::CustomerIdNumber),
+            Some(data::Field {
+                subfile_type: SubfileType::DL,
+                id: ElementId::CustomerIdNumber,
+                value: "2",
+            })
+        );
+        assert_eq!(data.get(ElementId::FamilyName), None);
+        assert_eq!(
+            data.get("ZVA")
+                .map(|field| (field.subfile_type, field.value)),
+            Some((SubfileType::JurisdictionSpecific('V'), "01"))
+        );
+
+        assert_eq!(
+            data.elements()
+                .map(|field| (field.id.to_string(), field.value))
+                .collect::<Vec<_>>(),
+            vec![("DAQ".to_string(), "2"), ("ZVA".to_string(), "01")]
+        );
+
+        assert_eq!(ElementId::from("DAQ"), ElementId::CustomerIdNumber);
+        assert_eq!(ElementId::from("ZVA"), ElementId::Other("ZVA".to_string()));
+        assert_eq!(
+            ElementId::DateOfBirth.definition().unwrap().latest().name,
+            "Date of Birth"
+        );
+    }
+
+    #[test]
+    fn test_owned_data() {
+        let input =
+            "@\n\x1e\rANSI 6360000101DL00290035DLDAQT64235789\nDCSSAMPLE\nDAU070 in\r".to_string();
+
+        let data = parse_barcode(&input).unwrap();
+        let json = serde_json::to_string(&data).unwrap();
+        let owned = data.into_owned();
+        drop(input);
+
+        let owned = std::thread::spawn(move || owned).join().unwrap();
+        assert_eq!(owned.customer_id_number().as_deref(), Some("T64235789"));
+        assert_eq!(owned.height(), Some(data::Height::Inches(70)));
+
+        let deserialized: OwnedData = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized.header, owned.header);
+        assert_eq!(deserialized.subfiles, owned.subfiles);
+        assert_eq!(
+            deserialized.raw.subfiles[0].elements,
+            owned.raw.subfiles[0].elements
+        );
+        assert_eq!(
+            deserialized.raw.subfiles[0].elements[1].value_span,
+            Span { start: 47, end: 53 }
+        );
+        assert_eq!(deserialized.input, owned.input);
+        assert_eq!(
+            &deserialized.input[deserialized.raw.subfiles[0].elements[1].value_span.range()],
+            b"SAMPLE"
+        );
+    }
+
+    #[test]
+    fn test_encode() {
+        for version_number in 0..=10 {
+            let payload = BarcodeBuilder::new(636000, version_number)
+                .jurisdiction_version_number(2)
+                .element(SubfileType::DL, "DAQ", "T64235789")
+                .element(SubfileType::JurisdictionSpecific('V'), "ZVA", "01")
+                .element(SubfileType::DL, "DCS", "SAMPLÉ")
+                .build()
+                .unwrap();
+
+            let report = parse_barcode_with(
+                &payload,
+                &ParseOptions {
+                    strictness: Strictness::Strict,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+            let data = report.data;
+
+            assert!(report.diagnostics.is_empty());
+            assert_eq!(data.header.version_number, version_number);
+            assert_eq!(
+                data.header.jurisdiction_version_number,
+                (version_number > 2).then_some(2)
+            );
+            assert_eq!(
+                data.subfiles[&SubfileType::DL]["DCS"].as_deref(),
+                Some("SAMPLÉ")
+            );
+            assert_eq!(
+                data.subfiles[&SubfileType::JurisdictionSpecific('V')]["ZVA"].as_deref(),
+                Some("01")
+            );
+            assert!(data.raw.leftover.is_empty());
+            assert_eq!(
+                data.raw_element(SubfileType::DL, "DCS")
+                    .map(|elem| elem.value.as_ref()),
+                Some(b"SAMPL\xc9".as_slice())
+            );
+        }
+
+        // Subfiles without elements are still terminated.
+        let input = "@\n\x1e\rANSI 6360000102DL00390008ZV00470001DLDAQ12\r\r";
+        let data = parse_barcode(input).unwrap();
+        assert_eq!(
+            BarcodeBuilder::from_data(&data).build().unwrap(),
+            input.as_bytes()
+        );
+
+        let cases = [
+            (
+                BarcodeBuilder::new(1_000_000, 10),
+                EncodeError::InvalidIssuerNumber {
+                    issuer_id: 1_000_000,
+                },
+            ),
+            (
+                BarcodeBuilder::new(636000, 10).element(SubfileType::DL, "DA", "X"),
+                EncodeError::InvalidElementId {
+                    id: "DA".to_string(),
+                },
+            ),
+            (
+                BarcodeBuilder::new(636000, 10).element(SubfileType::DL, "DAQ", "1\n2"),
+                EncodeError::InvalidValue {
+                    id: "DAQ".to_string(),
+                },
+            ),
+            (
+                BarcodeBuilder::new(636000, 10).element(SubfileType::DL, "DCS", "SAMPLE\u{141}"),
+                EncodeError::UnencodableCharacter {
+                    id: "DCS".to_string(),
+                    character: '\u{141}',
+                },
+            ),
+            (
+                BarcodeBuilder::new(636000, 10).element(SubfileType::DL, "DAQ", "1".repeat(9999)),
+                EncodeError::TooLong {
+                    subfile_type: SubfileType::DL,
+                },
+            ),
+        ];
+
+        for (builder, expected_error) in cases {
+            assert_eq!(builder.build().unwrap_err(), expected_error);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for entry in get_test_files() {
+            let input = std::fs::read(entry.path()).unwrap();
+            let data = parse_barcode_bytes(&input).unwrap();
+
+            let payload = BarcodeBuilder::from_data(&data).build().unwrap();
+            let encoded = parse_barcode_bytes(&payload).unwrap();
+            assert_eq!(encoded.subfiles, data.subfiles, "{:?}", entry.path());
+
+            let subfile_type = data.header.subfile_designators[0].subfile_type;
+            let decoded = DecodedData::from(data);
+            let payload = BarcodeBuilder::from_decoded(&decoded, subfile_type)
+                .build()
+                .unwrap();
+            let encoded = DecodedData::from(parse_barcode_bytes(&payload).unwrap());
+            assert_eq!(
+                format!("{encoded:?}"),
+                format!("{decoded:?}"),
+                "{:?}",
+                entry.path()
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_decoded() {
+        let name = data::Name {
+            family: "SAMPLE".to_string(),
+            first: "JOHN".to_string(),
+            middle: Some("Q".to_string()),
+            prefix: None,
+            suffix: None,
+            alias_family: None,
+            alias_given: None,
+            alias_suffix: None,
+            family_truncation: None,
+            first_truncation: None,
+            middle_truncation: None,
+        };
+
+        let cases = [
+            (
+                1,
+                636000,
+                vec![
+                    ("DAA", "SAMPLE,JOHN,Q"),
+                    ("DBB", "19900131"),
+                    ("DAU", "510"),
+                ],
+            ),
+            (
+                3,
+                636000,
+                vec![
+                    ("DCS", "SAMPLE"),
+                    ("DCT", "JOHN,Q"),
+                    ("DBB", "01311990"),
+                    ("DAU", "070 in"),
+                ],
+            ),
+            (
+                10,
+                636000,
+                vec![
+                    ("DCS", "SAMPLE"),
+                    ("DAC", "JOHN"),
+                    ("DAD", "Q"),
+                    ("DBB", "01311990"),
+                    ("DAU", "070 in"),
+                ],
+            ),
+            (
+                10,
+                636012,
+                vec![
+                    ("DCS", "SAMPLE"),
+                    ("DAC", "JOHN"),
+                    ("DBB", "19900131"),
+                    ("DAU", "070 in"),
+                ],
+            ),
+        ];
+
+        for (version, issuer_id, expected_elements) in cases {
+            let decoded = DecodedData {
+                issuer_id,
+                aamva_version: version,
+                jurisdiction_version: (version > 2).then_some(1),
+                document_expiration_date: None,
+                expiration: data::Expiration::Unknown,
+                name: Some(name.clone()),
+                document_issue_date: None,
+                date_of_birth: Some(jiff::civil::date(1990, 1, 31)),
+                partial_date_of_birth: Some(jiff::civil::date(1990, 1, 31).into()),
+                sex: None,
+                eye_color: None,
+                height: Some(data::Height::Inches(70)),
+                address: Some(data::Address {
+                    address_1: "123 MAIN ST".to_string(),
+                    address_2: None,
+                    city: "ANYTOWN".to_string(),
+                    jurisdiction_code: "VA".to_string(),
+                    postal_code: if issuer_id == 636012 {
+                        address::PostalCode::Canada("K1A 0B1".to_string())
+                    } else {
+                        address::PostalCode::UnitedStates {
+                            zip: "24450".to_string(),
+                            plus_four: Some("5700".to_string()),
+                        }
+                    },
+                }),
+                residence_address: (version == 1).then(|| data::Address {
+                    address_1: "456 OAK AVE".to_string(),
+                    address_2: None,
+                    city: "ANYTOWN".to_string(),
+                    jurisdiction_code: "VA".to_string(),
+                    postal_code: if issuer_id == 636012 {
+                        address::PostalCode::Canada("K1A 0B1".to_string())
+                    } else {
+                        address::PostalCode::UnitedStates {
+                            zip: "24450".to_string(),
+                            plus_four: Some("5700".to_string()),
+                        }
+                    },
+                }),
+                customer_id_number: None,
+                document_discriminator: None,
+                // Version 1 has no country element and infers it from the
+                // height, so it has to match.
+                country: Some(if issuer_id == 636012 {
+                    data::IssuerCountry::Canada
+                } else {
+                    data::IssuerCountry::UnitedStates
+                }),
+                hair_color: None,
+                place_of_birth: None,
+                audit_information: None,
+                inventory_control_information: None,
+                weight: None,
+                race: None,
+                card_revision_date: None,
+                under_age_until: data::UnderAgeUntil {
+                    under_18_until: None,
+                    under_19_until: None,
+                    under_21_until: None,
+                },
+                compliance_type: (version < 4).then_some(data::ComplianceType::Legacy),
+                organ_donor: (version == 1 || version >= 8).then_some(true),
+                veteran: (version >= 8).then_some(false),
+                limited_duration_document: None,
+                privileges: Some(data::Privileges {
+                    vehicle_classes: vec![
+                        data::VehicleClass::Other("D".to_string()),
+                        data::VehicleClass::M,
+                    ],
+                    restrictions: vec![data::Restriction::CorrectiveLenses],
+                    endorsements: vec![
+                        data::Endorsement::HazardousMaterials,
+                        data::Endorsement::SchoolBus,
+                    ],
+                    standard_vehicle_classes: if version >= 2 {
+                        vec![data::VehicleClass::C]
+                    } else {
+                        vec![]
+                    },
+                    vehicle_class_description: (version >= 2)
+                        .then(|| "PASSENGER VEHICLES".to_string()),
+                    ..Default::default()
+                }),
+                commercial: data::Commercial {
+                    is_commercial: version >= 2,
+                    federal_commercial_vehicle_codes: (version >= 2).then(|| "N".to_string()),
+                    hazmat_endorsement_expiration_date: (version >= 4)
+                        .then_some(jiff::civil::date(2030, 6, 30)),
+                },
+            };
+
+            let payload = BarcodeBuilder::from_decoded(&decoded, SubfileType::DL)
+                .build()
+                .unwrap();
+            let data = parse_barcode_bytes(&payload).unwrap();
+
+            for (id, value) in expected_elements {
+                assert_eq!(
+                    data.subfiles[&SubfileType::DL][id].as_deref(),
+                    Some(value),
+                    "{id} in version {version}"
+                );
+            }
+
+            assert_eq!(
+                format!("{:?}", DecodedData::from(data)),
+                format!("{decoded:?}")
+            );
+        }
+    }
+
+    #[test]
+    fn test_elements() {
+        let dac = elements::element("DAC").unwrap();
+        assert_eq!(dac.version(1).unwrap().name, "Given Name");
+        assert!(dac.version(2).is_none());
+        assert!(dac.latest().is_mandatory(SubfileType::ID));
+        assert!(
+            !elements::element("DCA")
+                .unwrap()
+                .latest()
+                .is_mandatory(SubfileType::ID)
+        );
+        assert!(elements::element("ZVA").is_none());
+
+        for id in data::DATE_ELEMENTS {
+            let definition = elements::element(id).unwrap().latest();
+            assert_eq!(definition.length, elements::Length::Fixed(8));
+            assert_eq!(definition.characters, elements::CharacterClass::Numeric);
+        }
+
+        for entry in get_test_files() {
+            let input = std::fs::read(entry.path()).unwrap();
+            let data = parse_barcode_bytes(&input).unwrap();
+
+            for subfile in &data.raw.subfiles {
+                if subfile.designator.subfile_type != SubfileType::DL
+                    && subfile.designator.subfile_type != SubfileType::ID
+                {
+                    continue;
+                }
+
+                for elem in &subfile.elements {
+                    let definition = elements::element(&elem.id)
+                        .and_then(|definition| definition.version(data.header.version_number))
+                        .unwrap_or_else(|| panic!("{} should be defined", elem.id));
+
+                    let value = elem.decoded_value(data.raw.encoding);
+                    let value = value.trim_end();
+                    assert!(value.len() <= definition.length.max() as usize);
+                    assert!(definition.characters.matches(value), "{}", elem.id);
+                }
+            }
         }
     }
 
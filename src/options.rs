@@ -0,0 +1,57 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::data::DateFormat;
+
+/// Options controlling how a barcode is parsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseOptions {
+    pub strictness: Strictness,
+    /// Read every date in this format instead of the one the issuer uses,
+    /// without falling back to the other format. The parsed data keeps the
+    /// format, so its date accessors and [`DecodedData`] use it too.
+    ///
+    /// [`DecodedData`]: crate::DecodedData
+    pub date_format: Option<DateFormat>,
+    /// How element values that are not plain ASCII are decoded.
+    pub encoding: TextEncoding,
+}
+
+/// How strictly a barcode must conform to the AAMVA standard.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Strictness {
+    /// Work around as many known issues as possible, recording each one as a
+    /// diagnostic.
+    #[default]
+    Lenient,
+    /// Reject any barcode that would need a workaround to be parsed.
+    Strict,
+}
+
+/// The character set of element values in a barcode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TextEncoding {
+    /// ISO-8859-1, the only character set besides ASCII that the standard
+    /// allows. Some Canadian jurisdictions use it for accented names.
+    #[default]
+    Latin1,
+    /// UTF-8, for input that was already decoded into text, such as the
+    /// `&str` given to [`parse_barcode`](crate::parse_barcode).
+    Utf8,
+}
+
+impl TextEncoding {
+    /// Decode an element value. ASCII values, and any valid value when
+    /// decoding UTF-8, are borrowed from the input.
+    pub fn decode(self, value: &[u8]) -> Cow<'_, str> {
+        match (self, std::str::from_utf8(value)) {
+            (_, Ok(value)) if value.is_ascii() => Cow::Borrowed(value),
+            (Self::Utf8, Ok(value)) => Cow::Borrowed(value),
+            (Self::Utf8, Err(_)) => String::from_utf8_lossy(value),
+            (Self::Latin1, _) => Cow::Owned(value.iter().map(|&b| char::from(b)).collect()),
+        }
+    }
+}
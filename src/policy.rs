@@ -0,0 +1,337 @@
+//! Deciding if a document should be accepted under a set of rules.
+
+use jiff::{ToSpan, civil::Date};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DecodedData,
+    age::AgeSource,
+    clock::Clock,
+    data::{ComplianceType, Expiration, IssuerCountry, IssuerIdentification},
+};
+
+/// Rules a document must meet to be accepted.
+///
+/// Every rule is off by default. A rule that needs a field the document does
+/// not have fails, so missing data is never accepted by accident. The
+/// exception is limited duration, which documents only have to state when it
+/// applies.
+///
+/// ```
+/// use aamva::policy::Policy;
+///
+/// let policy: Policy = serde_json::from_str(r#"{
+///     "reject_expired": true,
+///     "expiration_grace_days": 30,
+///     "minimum_age": 21
+/// }"#).unwrap();
+///
+/// assert_eq!(policy.minimum_age, Some(21));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Policy {
+    /// Reject documents past their expiration date. Documents that never
+    /// expire are accepted.
+    pub reject_expired: bool,
+    /// How many days after the expiration date a document is still
+    /// accepted.
+    pub expiration_grace_days: u16,
+    /// Reject documents that are not REAL ID compliant.
+    pub require_real_id: bool,
+    /// Reject documents issued for a limited duration, such as to temporary
+    /// residents. Documents that do not say are not limited duration.
+    pub reject_limited_duration: bool,
+    /// The youngest a cardholder may be, in years.
+    pub minimum_age: Option<i16>,
+    /// Reject documents with an issue date after the day they are checked.
+    pub reject_future_issue_date: bool,
+    /// The only countries documents may be from. The country is taken from
+    /// the document, or the issuer if the document does not say.
+    pub allowed_countries: Option<Vec<IssuerCountry>>,
+}
+
+/// The outcome of evaluating a policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Evaluation {
+    pub decision: Decision,
+    /// Every rule the document did not meet.
+    pub failures: Vec<PolicyFailure>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Decision {
+    Accept,
+    Reject,
+}
+
+/// A rule a document did not meet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "rule", rename_all = "snake_case")]
+pub enum PolicyFailure {
+    /// The document expired, including any grace period.
+    Expired { expiration_date: Date },
+    /// Expired documents are rejected but the document's expiration date was
+    /// missing or could not be parsed.
+    MissingExpirationDate,
+    /// The document is not REAL ID compliant.
+    NotRealId {
+        compliance_type: Option<ComplianceType>,
+    },
+    /// The document was issued for a limited duration.
+    LimitedDuration,
+    /// The cardholder is younger than the minimum age.
+    Underage { minimum_age: i16, source: AgeSource },
+    /// There was a minimum age but the document had no date of birth.
+    MissingDateOfBirth,
+    /// The document was issued after the day it was checked.
+    IssuedInFuture { issue_date: Date },
+    /// Future issue dates are rejected but the document had no issue date.
+    MissingIssueDate,
+    /// The document is from a country that is not allowed.
+    CountryNotAllowed { country: Option<IssuerCountry> },
+}
+
+impl std::fmt::Display for PolicyFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Expired { expiration_date } => write!(f, "document expired {expiration_date}"),
+            Self::MissingExpirationDate => write!(f, "document has no expiration date"),
+            Self::NotRealId { compliance_type } => {
+                write!(f, "document is not real id compliant: {compliance_type:?}")
+            }
+            Self::LimitedDuration => write!(f, "document is limited duration"),
+            Self::Underage { minimum_age, .. } => {
+                write!(f, "cardholder is younger than {minimum_age}")
+            }
+            Self::MissingDateOfBirth => write!(f, "document has no date of birth"),
+            Self::IssuedInFuture { issue_date } => {
+                write!(f, "document was issued in the future on {issue_date}")
+            }
+            Self::MissingIssueDate => write!(f, "document has no issue date"),
+            Self::CountryNotAllowed {
+                country: Some(country),
+            } => write!(f, "documents from {country:?} are not allowed"),
+            Self::CountryNotAllowed { country: None } => {
+                write!(f, "document is not from a known country")
+            }
+        }
+    }
+}
+
+impl Policy {
+    /// Evaluate a document as of a date.
+    pub fn evaluate(&self, data: &DecodedData, on: Date) -> Evaluation {
+        let mut failures = Vec::new();
+
+        if self.reject_expired {
+            match data.expiration {
+                Expiration::On(expiration_date)
+                    if on
+                        > expiration_date
+                            .saturating_add(i32::from(self.expiration_grace_days).days()) =>
+                {
+                    failures.push(PolicyFailure::Expired { expiration_date })
+                }
+                Expiration::On(_) | Expiration::NonExpiring => (),
+                Expiration::Unknown => failures.push(PolicyFailure::MissingExpirationDate),
+            }
+        }
+
+        if self.require_real_id && data.compliance_type != Some(ComplianceType::FullyCompliant) {
+            failures.push(PolicyFailure::NotRealId {
+                compliance_type: data.compliance_type.clone(),
+            });
+        }
+
+        if self.reject_limited_duration && data.limited_duration_document == Some(true) {
+            failures.push(PolicyFailure::LimitedDuration);
+        }
+
+        if let Some(minimum_age) = self.minimum_age {
+            match data.is_at_least(minimum_age, on) {
+                Some(check) if !check.value => failures.push(PolicyFailure::Underage {
+                    minimum_age,
+                    source: check.source,
+                }),
+                Some(_) => (),
+                None => failures.push(PolicyFailure::MissingDateOfBirth),
+            }
+        }
+
+        if self.reject_future_issue_date {
+            match data.document_issue_date {
+                Some(issue_date) if issue_date > on => {
+                    failures.push(PolicyFailure::IssuedInFuture { issue_date })
+                }
+                Some(_) => (),
+                None => failures.push(PolicyFailure::MissingIssueDate),
+            }
+        }
+
+        if let Some(allowed_countries) = &self.allowed_countries {
+            let country = data.country.or_else(|| {
+                IssuerIdentification::try_from(data.issuer_id)
+                    .ok()
+                    .map(|issuer| issuer.country())
+            });
+
+            if !country.is_some_and(|country| allowed_countries.contains(&country)) {
+                failures.push(PolicyFailure::CountryNotAllowed { country });
+            }
+        }
+
+        Evaluation {
+            decision: if failures.is_empty() {
+                Decision::Accept
+            } else {
+                Decision::Reject
+            },
+            failures,
+        }
+    }
+
+    /// Evaluate a document as of today.
+    pub fn evaluate_today(&self, data: &DecodedData, clock: &impl Clock) -> Evaluation {
+        self.evaluate(data, clock.today())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+    use crate::tests::{LICENSE_FOLDER, parse_elements};
+
+    #[test]
+    fn test_policy() {
+        let policy: Policy = serde_json::from_str(
+            r#"{
+                "reject_expired": true,
+                "expiration_grace_days": 30,
+                "require_real_id": true,
+                "reject_limited_duration": true,
+                "minimum_age": 21,
+                "reject_future_issue_date": true,
+                "allowed_countries": ["UnitedStates"]
+            }"#,
+        )
+        .unwrap();
+
+        let read = |name: &str| {
+            let input = std::fs::read(format!("{LICENSE_FOLDER}/VA-V4/{name}.txt")).unwrap();
+            DecodedData::from(crate::parse_barcode_bytes(&input).unwrap())
+        };
+
+        let evaluation = policy.evaluate(&read("VA-REAL-21"), date(2025, 1, 1));
+        assert_eq!(evaluation.decision, Decision::Accept);
+        assert!(evaluation.failures.is_empty());
+
+        let evaluation = policy.evaluate(&read("VA-REAL-U21"), date(2023, 5, 1));
+        assert_eq!(evaluation.decision, Decision::Reject);
+        assert_eq!(
+            evaluation.failures,
+            vec![
+                PolicyFailure::Underage {
+                    minimum_age: 21,
+                    source: AgeSource::DateOfBirth,
+                },
+                PolicyFailure::IssuedInFuture {
+                    issue_date: date(2023, 5, 14),
+                },
+            ]
+        );
+
+        let data = read("VA-21");
+        assert_eq!(
+            policy.evaluate(&data, date(2031, 4, 22)).failures,
+            vec![PolicyFailure::NotRealId {
+                compliance_type: Some(ComplianceType::NonCompliant),
+            }]
+        );
+        assert_eq!(
+            policy.evaluate(&data, date(2031, 4, 23)).failures,
+            vec![
+                PolicyFailure::Expired {
+                    expiration_date: date(2031, 3, 23),
+                },
+                PolicyFailure::NotRealId {
+                    compliance_type: Some(ComplianceType::NonCompliant),
+                },
+            ]
+        );
+
+        let policy = Policy {
+            allowed_countries: Some(vec![IssuerCountry::Canada]),
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.evaluate(&data, date(2025, 1, 1)).failures,
+            vec![PolicyFailure::CountryNotAllowed {
+                country: Some(IssuerCountry::UnitedStates),
+            }]
+        );
+
+        assert!(serde_json::from_str::<Policy>(r#"{"minimum_agee": 21}"#).is_err());
+        assert_eq!(
+            Policy::default().evaluate(&data, date(2099, 1, 1)).decision,
+            Decision::Accept
+        );
+    }
+
+    #[test]
+    fn test_non_expiring() {
+        let policy = Policy {
+            reject_expired: true,
+            ..Default::default()
+        };
+
+        let cases = [
+            ("99999999", vec![]),
+            ("00000000", vec![]),
+            ("12319999", vec![]),
+            ("13452031", vec![PolicyFailure::MissingExpirationDate]),
+            ("0", vec![PolicyFailure::MissingExpirationDate]),
+            (
+                "07042031",
+                vec![PolicyFailure::Expired {
+                    expiration_date: date(2031, 7, 4),
+                }],
+            ),
+        ];
+
+        for (value, expected) in cases {
+            let data = DecodedData::from(parse_elements(636014, 10, &[("DBA", value)]));
+            assert_eq!(
+                policy.evaluate(&data, date(2040, 1, 1)).failures,
+                expected,
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_limited_duration() {
+        let policy = Policy {
+            reject_limited_duration: true,
+            ..Default::default()
+        };
+
+        let cases = [
+            (vec![("DAQ", "1")], vec![]),
+            (vec![("DDD", "0")], vec![]),
+            (vec![("DDD", "1")], vec![PolicyFailure::LimitedDuration]),
+        ];
+
+        for (elements, expected) in cases {
+            let data = DecodedData::from(parse_elements(636000, 10, &elements));
+            assert_eq!(
+                policy.evaluate(&data, date(2025, 1, 1)).failures,
+                expected,
+                "{elements:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::{borrow::Cow, ops::Range};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SubfileDesignator, TextEncoding};
+
+/// Everything read from a barcode in the order it appeared, without values
+/// being trimmed or otherwise cleaned up.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct RawData<'a> {
+    /// Every subfile that could be parsed, including repeated subfiles and
+    /// subfiles of unknown types.
+    pub subfiles: Vec<RawSubfile<'a>>,
+    /// Bytes that were not part of the header or any parsed subfile.
+    pub leftover: Vec<RawSegment<'a>>,
+    /// How element values were decoded into [`Data::subfiles`].
+    ///
+    /// [`Data::subfiles`]: crate::Data::subfiles
+    #[serde(default)]
+    pub encoding: TextEncoding,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct RawSubfile<'a> {
+    pub designator: SubfileDesignator,
+    /// Where the subfile's data was read from, after any clamping.
+    pub span: Span,
+    /// Elements in the order they appeared, including duplicates.
+    pub elements: Vec<RawElement<'a>>,
+    /// Bytes at the end of the subfile that could not be parsed as elements.
+    pub trailing: Cow<'a, [u8]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct RawElement<'a> {
+    pub id: Cow<'a, str>,
+    /// The value exactly as it appeared, without its terminator.
+    pub value: Cow<'a, [u8]>,
+    pub id_span: Span,
+    pub value_span: Span,
+}
+
+impl RawElement<'_> {
+    /// The value decoded into Unicode, without any trimming.
+    pub fn decoded_value(&self, encoding: TextEncoding) -> Cow<'_, str> {
+        encoding.decode(&self.value)
+    }
+
+    /// Copy everything borrowed from the input so the element can outlive it.
+    pub fn into_owned(self) -> RawElement<'static> {
+        RawElement {
+            id: Cow::Owned(self.id.into_owned()),
+            value: Cow::Owned(self.value.into_owned()),
+            id_span: self.id_span,
+            value_span: self.value_span,
+        }
+    }
+}
+
+/// A range of bytes in the original input, from `start` up to but not
+/// including `end`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    /// The span of `slice`, which must have been borrowed from `input`.
+    pub(crate) fn of(input: &[u8], slice: &[u8]) -> Self {
+        let start = (slice.as_ptr() as usize).saturating_sub(input.as_ptr() as usize);
+
+        Self {
+            start,
+            end: start + slice.len(),
+        }
+    }
+
+    /// The span of everything a parser consumed, given the input before and
+    /// after it ran.
+    pub(crate) fn between(input: &[u8], before: &[u8], after: &[u8]) -> Self {
+        Self::of(input, &before[..before.len() - after.len()])
+    }
+
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// A run of bytes at a byte offset into the input.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "web", derive(tsify::Tsify))]
+pub struct RawSegment<'a> {
+    pub offset: usize,
+    pub bytes: Cow<'a, [u8]>,
+}
+
+/// Find every part of the input not covered by one of the given byte ranges.
+pub(crate) fn leftover_segments(
+    input: &[u8],
+    mut covered: Vec<Range<usize>>,
+) -> Vec<RawSegment<'_>> {
+    covered.sort_by_key(|range| range.start);
+
+    let mut segments = Vec::new();
+    let mut position = 0;
+
+    for range in covered {
+        if range.start > position {
+            segments.push(RawSegment {
+                offset: position,
+                bytes: Cow::Borrowed(&input[position..range.start]),
+            });
+        }
+
+        position = position.max(range.end);
+    }
+
+    if position < input.len() {
+        segments.push(RawSegment {
+            offset: position,
+            bytes: Cow::Borrowed(&input[position..]),
+        });
+    }
+
+    segments
+}
+
+impl RawData<'_> {
+    /// Copy everything borrowed from the input so the data can outlive it.
+    pub fn into_owned(self) -> RawData<'static> {
+        RawData {
+            subfiles: self
+                .subfiles
+                .into_iter()
+                .map(RawSubfile::into_owned)
+                .collect(),
+            leftover: self
+                .leftover
+                .into_iter()
+                .map(RawSegment::into_owned)
+                .collect(),
+            encoding: self.encoding,
+        }
+    }
+}
+
+impl RawSubfile<'_> {
+    /// Copy everything borrowed from the input so the subfile can outlive it.
+    pub fn into_owned(self) -> RawSubfile<'static> {
+        RawSubfile {
+            designator: self.designator,
+            span: self.span,
+            elements: self
+                .elements
+                .into_iter()
+                .map(RawElement::into_owned)
+                .collect(),
+            trailing: Cow::Owned(self.trailing.into_owned()),
+        }
+    }
+}
+
+impl RawSegment<'_> {
+    /// Copy the bytes borrowed from the input so the segment can outlive it.
+    pub fn into_owned(self) -> RawSegment<'static> {
+        RawSegment {
+            offset: self.offset,
+            bytes: Cow::Owned(self.bytes.into_owned()),
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use serde::Serialize;
+
+use crate::{Data, ParseError, Strictness, SubfileType, data::DateFormat};
+
+/// Data parsed from a barcode along with everything unusual that was noticed
+/// while parsing it.
+#[derive(Debug, Serialize)]
+pub struct ParseReport<'a> {
+    pub data: Data<'a>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A workaround that was applied or a problem that was skipped over while
+/// parsing a barcode.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Diagnostic {
+    /// The issuer identification number did not match a known jurisdiction.
+    UnknownIssuer { issuer_id: u32 },
+    /// The header did not declare exactly three separator characters, so the
+    /// standard separators were used instead.
+    InvalidSeparators { declared: Vec<u8> },
+    /// The number of entries in the header did not match the number of
+    /// subfile designators that followed it.
+    EntryCountMismatch { declared: u8, found: usize },
+    /// The input ended before every subfile designator the header declared,
+    /// usually because the scanner cut the read short.
+    TruncatedSubfileDesignators { declared: u8, found: usize },
+    /// A subfile offset was missing or zero, so it was guessed from the data.
+    GuessedSubfileOffset {
+        subfile_type: SubfileType,
+        offset: u32,
+    },
+    /// The known off-by-one offset in version 1 South Carolina barcodes was
+    /// corrected.
+    SouthCarolinaOffsetFix { subfile_type: SubfileType },
+    /// A subfile extended past the end of the input and was shortened.
+    ClampedSubfileLength {
+        subfile_type: SubfileType,
+        length: u32,
+        clamped_length: u32,
+    },
+    /// A `DL`, `EN`, or `ID` subfile did not start with its subfile type.
+    MissingSubfileTypeMarker { subfile_type: SubfileType },
+    /// An element did not start with the prefix expected for its subfile.
+    WrongElementPrefix {
+        subfile_type: SubfileType,
+        id: String,
+    },
+    /// A subfile could not be parsed and was left out of the data.
+    DroppedSubfile {
+        subfile_type: SubfileType,
+        error: ParseError,
+    },
+    /// An element that should contain a date was not eight digits long.
+    MalformedDate { id: String, value: String },
+    /// An element that should contain a date could not be parsed as one.
+    UnparseableDate { id: String, value: String },
+    /// A date was not valid in the issuer's format, so it was read in the
+    /// other format.
+    DateFormatFallback {
+        id: String,
+        value: String,
+        expected: DateFormat,
+        used: DateFormat,
+    },
+    /// A date did not have a plausible year in any format, so it may have
+    /// been read in the wrong format.
+    AmbiguousDate {
+        id: String,
+        value: String,
+        used: DateFormat,
+    },
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownIssuer { issuer_id } => write!(f, "unknown issuer {issuer_id}"),
+            Self::InvalidSeparators { declared } => {
+                write!(f, "header declared invalid separators: {declared:?}")
+            }
+            Self::EntryCountMismatch { declared, found } => write!(
+                f,
+                "header declared {declared} entries but had {found} subfile designators"
+            ),
+            Self::TruncatedSubfileDesignators { declared, found } => write!(
+                f,
+                "input ended after {found} of {declared} subfile designators"
+            ),
+            Self::GuessedSubfileOffset {
+                subfile_type,
+                offset,
+            } => write!(f, "guessed offset {offset} for subfile {subfile_type}"),
+            Self::SouthCarolinaOffsetFix { subfile_type } => {
+                write!(
+                    f,
+                    "applied south carolina offset fix to subfile {subfile_type}"
+                )
+            }
+            Self::ClampedSubfileLength {
+                subfile_type,
+                length,
+                clamped_length,
+            } => write!(
+                f,
+                "clamped length of subfile {subfile_type} from {length} to {clamped_length}"
+            ),
+            Self::MissingSubfileTypeMarker { subfile_type } => {
+                write!(f, "subfile {subfile_type} did not start with its type")
+            }
+            Self::WrongElementPrefix { subfile_type, id } => {
+                write!(
+                    f,
+                    "element in subfile {subfile_type} had wrong ID prefix: {id}"
+                )
+            }
+            Self::DroppedSubfile {
+                subfile_type,
+                error,
+            } => write!(f, "dropped subfile {subfile_type}: {error}"),
+            Self::MalformedDate { id, value } => {
+                write!(f, "date in {id} was not eight digits: {value}")
+            }
+            Self::UnparseableDate { id, value } => {
+                write!(f, "could not parse date in {id}: {value}")
+            }
+            Self::DateFormatFallback {
+                id,
+                value,
+                expected,
+                used,
+            } => write!(
+                f,
+                "date in {id} was not valid as {expected:?} so was read as {used:?}: {value}"
+            ),
+            Self::AmbiguousDate { id, value, used } => {
+                write!(
+                    f,
+                    "date in {id} had no plausible year and was read as {used:?}: {value}"
+                )
+            }
+        }
+    }
+}
+
+/// Collects diagnostics while a barcode is parsed, turning them into errors
+/// when parsing strictly.
+pub(crate) struct Diagnostics<'a> {
+    input: &'a [u8],
+    strictness: Strictness,
+    pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Diagnostics<'a> {
+    pub(crate) fn new(input: &'a [u8], strictness: Strictness) -> Self {
+        Self {
+            input,
+            strictness,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub(crate) fn is_strict(&self) -> bool {
+        self.strictness == Strictness::Strict
+    }
+
+    /// Record a diagnostic that does not affect conformance.
+    pub(crate) fn note(&mut self, diagnostic: Diagnostic) {
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Record a diagnostic for a conformance problem found at `at`, a slice of
+    /// the input, rejecting it when parsing strictly.
+    pub(crate) fn report(
+        &mut self,
+        at: &[u8],
+        context: &'static str,
+        diagnostic: Diagnostic,
+    ) -> Result<(), ParseError> {
+        if self.is_strict() {
+            return Err(ParseError::Nonconforming {
+                offset: (at.as_ptr() as usize).saturating_sub(self.input.as_ptr() as usize),
+                context,
+                diagnostic: Box::new(diagnostic),
+            });
+        }
+
+        self.diagnostics.push(diagnostic);
+        Ok(())
+    }
+}